@@ -0,0 +1,133 @@
+//! Compares the sparse `Conway` engine with a dense array that grows by one cell in every direction per generation.
+//! Run with `cargo bench --bench conway`.
+use advent_of_code::template::runner::{measure, Measurement};
+use advent_of_code::{neighbour_offsets, Conway, Grid};
+
+/// An 8x8 starting slice, the size of a Conway cubes puzzle input.
+const INPUT: &str = "\
+#.#.##..
+..#....#
+##.#.#.#
+.#...#..
+#..##.#.
+...#..##
+.##..#..
+#...#.##";
+
+const GENERATIONS: usize = 6;
+
+/// An `N`-dimensional array of cells, stored with the first axis varying fastest.
+struct Dense<const N: usize> {
+    cells: Vec<bool>,
+    size: [usize; N],
+}
+
+impl<const N: usize> Dense<N> {
+    fn from_grid(grid: &Grid<bool>) -> Self {
+        let mut size = [1; N];
+        size[0] = grid.width();
+        size[1] = grid.height();
+
+        Self {
+            cells: grid.values().copied().collect(),
+            size,
+        }
+    }
+
+    fn index(&self, point: [usize; N]) -> usize {
+        point
+            .iter()
+            .zip(self.size)
+            .rev()
+            .fold(0, |index, (&axis, size)| index * size + axis)
+    }
+
+    fn point(&self, mut index: usize) -> [usize; N] {
+        let mut point = [0; N];
+        for (axis, size) in point.iter_mut().zip(self.size) {
+            *axis = index % size;
+            index /= size;
+        }
+        point
+    }
+
+    /// Computes the next generation into an array that is larger by one cell on each side.
+    fn step(&self, offsets: &[[i32; N]]) -> Self {
+        let mut next = Self {
+            cells: vec![],
+            size: self.size.map(|size| size + 2),
+        };
+
+        next.cells = (0..next.size.iter().product())
+            .map(|index| {
+                let point = next.point(index);
+                let neighbours = offsets
+                    .iter()
+                    .filter(|offset| {
+                        let mut neighbour = [0; N];
+                        for axis in 0..N {
+                            // the previous generation is shifted by one cell on each axis.
+                            let coordinate = point[axis] as isize - 1 + offset[axis] as isize;
+                            if coordinate < 0 || coordinate >= self.size[axis] as isize {
+                                return false;
+                            }
+                            neighbour[axis] = coordinate as usize;
+                        }
+                        self.cells[self.index(neighbour)]
+                    })
+                    .count();
+
+                let active = point
+                    .iter()
+                    .zip(self.size)
+                    .all(|(&axis, size)| axis >= 1 && axis <= size)
+                    && self.cells[self.index(point.map(|axis| axis - 1))];
+
+                neighbours == 3 || (active && neighbours == 2)
+            })
+            .collect();
+
+        next
+    }
+}
+
+fn run_sparse<const N: usize>(grid: &Grid<bool>) -> usize {
+    let mut cubes = Conway::<N>::from_grid(grid, |&active| active);
+    cubes.run(GENERATIONS)
+}
+
+fn run_dense<const N: usize>(grid: &Grid<bool>) -> usize {
+    let offsets = neighbour_offsets::<N>();
+    let mut cubes = Dense::<N>::from_grid(grid);
+    for _ in 0..GENERATIONS {
+        cubes = cubes.step(&offsets);
+    }
+    cubes.cells.iter().filter(|&&active| active).count()
+}
+
+fn format_measurement(measurement: &Measurement) -> String {
+    format!("{:.1?} ± {:.1?}", measurement.mean, measurement.stddev)
+}
+
+fn compare<const N: usize>(grid: &Grid<bool>) {
+    let sparse = run_sparse::<N>(grid);
+    let dense = run_dense::<N>(grid);
+    assert_eq!(sparse, dense, "sparse and dense results differ in {N}D");
+
+    println!(
+        "| {N}D | {sparse} | {} | {} |",
+        format_measurement(&measure(run_sparse::<N>, grid)),
+        format_measurement(&measure(run_dense::<N>, grid)),
+    );
+}
+
+fn main() {
+    let grid = Grid::parse(INPUT, |c| Some(c == '#')).unwrap();
+
+    println!("{GENERATIONS} generations of an 8x8 slice:\n");
+    println!("| Dimensions | Active | Sparse | Dense |");
+    println!("| :---: | :---: | :---: | :---: |");
+    compare::<2>(&grid);
+    compare::<3>(&grid);
+    compare::<4>(&grid);
+}
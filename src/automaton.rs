@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+use crate::{Grid, Position, NEIGHBOURS_4, NEIGHBOURS_8};
+
+/// A cell of a [`Grid`] together with its surroundings, as passed to the rule of an [`Automaton`].
+#[derive(Debug, Clone, Copy)]
+pub struct Neighbourhood<'a, T> {
+    pub grid: &'a Grid<T>,
+    pub position: Position,
+}
+
+impl<'a, T> Neighbourhood<'a, T> {
+    /// Returns the value of the cell itself.
+    pub fn cell(&self) -> &'a T {
+        &self.grid[self.position]
+    }
+
+    /// Returns the orthogonal neighbours of the cell.
+    pub fn orthogonal(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let grid = self.grid;
+        grid.neighbours(self.position, &NEIGHBOURS_4)
+            .map(move |p| &grid[p])
+    }
+
+    /// Returns the orthogonal and diagonal neighbours of the cell.
+    pub fn adjacent(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let grid = self.grid;
+        grid.neighbours(self.position, &NEIGHBOURS_8)
+            .map(move |p| &grid[p])
+    }
+
+    /// Returns the first cell in each of the eight directions that is not `transparent`.
+    pub fn visible(
+        &self,
+        transparent: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        let grid = self.grid;
+        let position = self.position;
+        NEIGHBOURS_8.iter().filter_map(move |&direction| {
+            grid.ray(position, direction)
+                .map(|p| &grid[p])
+                .find(|cell| !transparent(cell))
+        })
+    }
+}
+
+/// A detected cycle of an [`Automaton`]: the state of generation `start` recurs every `length` generations.
+/// A stable state is a cycle of length 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A cellular automaton on a [`Grid`]. Every generation, the rule computes the next value of each cell
+/// from its [`Neighbourhood`] in the current generation.
+///
+/// ```
+/// # use advent_of_code::{Automaton, Grid};
+/// // a blinker oscillates with a period of two generations.
+/// let grid: Grid<char> = ".....\n..#..\n..#..\n..#..\n.....".parse().unwrap();
+/// let mut automaton = Automaton::new(grid, |n| {
+///     let alive = n.adjacent().filter(|&&c| c == '#').count();
+///     if alive == 3 || (alive == 2 && *n.cell() == '#') { '#' } else { '.' }
+/// });
+/// assert_eq!(automaton.run_until_cycle().length, 2);
+/// ```
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    rule: R,
+    generation: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(Neighbourhood<'_, T>) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// The current state.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    /// The number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation. Returns whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut changed = false;
+
+        for position in self.current.positions() {
+            let value = (self.rule)(Neighbourhood {
+                grid: &self.current,
+                position,
+            });
+            changed |= value != self.current[position];
+            self.next[position] = value;
+        }
+
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Computes `generations` generations.
+    pub fn run(&mut self, generations: usize) -> &Grid<T> {
+        for _ in 0..generations {
+            self.step();
+        }
+        &self.current
+    }
+
+    /// Computes generations until one does not change any cell, and returns the stable state.
+    /// Does not terminate if the automaton never stabilizes, see [`Automaton::run_until_cycle`].
+    pub fn run_until_stable(&mut self) -> &Grid<T> {
+        while self.step() {}
+        &self.current
+    }
+
+    /// Computes generations until a previous state recurs.
+    /// The automaton is left at the first repetition, i.e. generation `start + length`.
+    pub fn run_until_cycle(&mut self) -> Cycle
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+
+        loop {
+            if let Some(start) = seen.insert(self.current.clone(), self.generation) {
+                return Cycle {
+                    start,
+                    length: self.generation - start,
+                };
+            }
+            self.step();
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Automaton, Cycle, Neighbourhood};
+    use crate::Grid;
+
+    fn life(n: Neighbourhood<'_, char>) -> char {
+        let alive = n.adjacent().filter(|&&c| c == '#').count();
+        if alive == 3 || (alive == 2 && *n.cell() == '#') {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    #[test]
+    fn steps_generations() {
+        let grid: Grid<char> = ".....\n..#..\n..#..\n..#..\n.....".parse().unwrap();
+        let mut automaton = Automaton::new(grid.clone(), life);
+
+        assert!(automaton.step());
+        assert_eq!(
+            automaton.grid().to_string(),
+            ".....\n.....\n.###.\n.....\n....."
+        );
+        assert_eq!(automaton.run(3), &grid);
+        assert_eq!(automaton.generation(), 4);
+    }
+
+    #[test]
+    fn runs_until_stable() {
+        let grid: Grid<char> = "....\n.##.\n.#..\n....".parse().unwrap();
+        let mut automaton = Automaton::new(grid, life);
+
+        assert_eq!(
+            automaton.run_until_stable().to_string(),
+            "....\n.##.\n.##.\n...."
+        );
+        assert_eq!(automaton.generation(), 2);
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let grid: Grid<char> = "...\n###\n...".parse().unwrap();
+        let mut automaton = Automaton::new(grid, life);
+        assert_eq!(
+            automaton.run_until_cycle(),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+
+        let grid: Grid<char> = "##.\n#..\n...".parse().unwrap();
+        let mut automaton = Automaton::new(grid, life);
+        assert_eq!(
+            automaton.run_until_cycle(),
+            Cycle {
+                start: 1,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn finds_visible_cells() {
+        let grid: Grid<char> = "#.#\n...\nL.#".parse().unwrap();
+        let n = Neighbourhood {
+            grid: &grid,
+            position: (1, 1),
+        };
+
+        let mut visible: Vec<_> = n.visible(|&c| c == '.').copied().collect();
+        visible.sort_unstable();
+        assert_eq!(visible, ['#', '#', '#', 'L']);
+        assert_eq!(n.orthogonal().filter(|&&c| c != '.').count(), 0);
+    }
+}
@@ -1,20 +1,6 @@
-advent_of_code::solution!(1);
+use advent_of_code::Input;
 
-use std::num::ParseIntError;
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-enum ParseError {
-    #[error(transparent)]
-    ParseIntError(#[from] ParseIntError),
-}
-
-fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
-    input
-        .lines()
-        .map(|line| line.trim().parse().map_err(ParseError::ParseIntError))
-        .collect()
-}
+advent_of_code::solution!(2020, 1);
 
 /// Find two elements that summed together equal `sum`.
 fn find_sum_pair(s: &[u32], sum: u32) -> Option<(usize, usize)> {
@@ -48,15 +34,15 @@ fn find_sum_triple(s: &[u32], sum: u32) -> Option<(usize, usize, usize)> {
     None
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let report = parse(input).unwrap();
+pub fn part_one(input: &Input) -> Option<String> {
+    let report: Vec<u32> = input.ints();
     let (i, j) = find_sum_pair(&report, 2020).unwrap();
     let product = report[i] * report[j];
     product.to_string().into()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let report = parse(input).unwrap();
+pub fn part_two(input: &Input) -> Option<String> {
+    let report: Vec<u32> = input.ints();
     let (i, j, k) = find_sum_triple(&report, 2020).unwrap();
     let product = report[i] * report[j] * report[k];
     product.to_string().into()
@@ -99,7 +85,7 @@ mod tests {
             675
             1456
         "};
-        let result = part_one(INPUT).unwrap();
+        let result = part_one(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "514579");
     }
 
@@ -113,7 +99,7 @@ mod tests {
             675
             1456
         "};
-        let result = part_two(INPUT).unwrap();
+        let result = part_two(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "241861950");
     }
 }
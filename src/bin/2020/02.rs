@@ -1,26 +1,23 @@
+use advent_of_code::parse::parse_all;
 use parser::password_list;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2020, 2);
 
 mod parser {
 
+    use advent_of_code::parse::{lines, unsigned};
     use nom::{
         bytes::complete::tag,
-        character::complete::{anychar, char, digit1, newline, not_line_ending, space1},
-        combinator::{map, map_res},
-        multi::separated_list0,
+        character::complete::{anychar, char, not_line_ending, space1},
+        combinator::map,
         sequence::{separated_pair, tuple},
         IResult,
     };
 
     use crate::{PasswordEntry, PasswordList, PasswordPolicy};
 
-    fn integer(input: &str) -> IResult<&str, u8> {
-        map_res(digit1, str::parse)(input)
-    }
-
     fn range(input: &str) -> IResult<&str, (u8, u8)> {
-        separated_pair(integer, char('-'), integer)(input)
+        separated_pair(unsigned, char('-'), unsigned)(input)
     }
 
     pub fn password_policy(input: &str) -> IResult<&str, PasswordPolicy> {
@@ -41,7 +38,7 @@ mod parser {
     }
 
     pub fn password_list(input: &str) -> IResult<&str, PasswordList> {
-        separated_list0(newline, password_entry)(input)
+        lines(password_entry)(input)
     }
 
     #[cfg(test)]
@@ -136,8 +133,12 @@ impl PasswordEntry {
     }
 }
 
+fn parse(input: &str) -> PasswordList {
+    parse_all(password_list, input).unwrap_or_else(|e| panic!("{e}"))
+}
+
 pub fn part_one(input: &str) -> Option<String> {
-    let (_, list) = password_list(input).unwrap();
+    let list = parse(input);
     list.iter()
         .filter(|e| e.is_valid_1())
         .count()
@@ -146,7 +147,7 @@ pub fn part_one(input: &str) -> Option<String> {
 }
 
 pub fn part_two(input: &str) -> Option<String> {
-    let (_, list) = password_list(input).unwrap();
+    let list = parse(input);
     list.iter()
         .filter(|e| e.is_valid_2())
         .count()
@@ -241,6 +242,12 @@ mod tests {
         );
     }
 
+    #[test]
+    #[should_panic(expected = "parse error at line 2, column 3")]
+    fn test_malformed_line() {
+        parse("1-3 a: abcde\n1-x b: cdefg\n");
+    }
+
     #[test]
     fn test_part_one() {
         const INPUT: &str = indoc! {"
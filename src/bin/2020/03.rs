@@ -1,54 +1,24 @@
-advent_of_code::solution!(3);
+use advent_of_code::Grid;
 
-struct Forest {
-    trees: Vec<bool>,
-    width: usize,
-    height: usize,
-}
+advent_of_code::solution!(2020, 3);
 
-impl Forest {
-    fn index(&self, x: usize, y: usize) -> usize {
-        y * self.width + (x % self.width)
-    }
-
-    pub fn has_tree(&self, x: usize, y: usize) -> bool {
-        self.trees[self.index(x, y)]
-    }
+/// The trees of the forest, repeating to the right.
+struct Forest(Grid<bool>);
 
+impl Forest {
     /// Traverse the forest using the pattern `right` and `down`,
     /// returning the number of trees encountered.
     pub fn traverse(&self, right: usize, down: usize) -> usize {
-        let mut x = 0;
-        let mut y = 0;
-        let mut count = 0;
-
-        while y < self.height {
-            if self.has_tree(x, y) {
-                count += 1;
-            }
-            x += right;
-            y += down;
-        }
-
-        count
+        (0..self.0.height())
+            .step_by(down)
+            .enumerate()
+            .filter(|&(step, y)| *self.0.get_wrapping(((step * right) as isize, y as isize)))
+            .count()
     }
 }
 
 fn parse(input: &str) -> Forest {
-    let mut lines = input.lines();
-    let first = lines.next().unwrap();
-    let width = first.len();
-    let is_tree = |c| c == '#';
-    let mut trees: Vec<bool> = first.chars().map(is_tree).collect();
-    for row in lines {
-        trees.extend(row.chars().map(is_tree));
-    }
-    let height = trees.len() / width;
-    Forest {
-        trees,
-        width,
-        height,
-    }
+    Forest(Grid::parse(input, |c| Some(c == '#')).unwrap())
 }
 
 pub fn part_one(input: &str) -> Option<String> {
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(4);
+use advent_of_code::Input;
+
+advent_of_code::solution!(2020, 4);
 
 #[derive(Debug, PartialEq, Eq)]
 struct Passport<'a>(HashMap<&'a str, &'a str>);
@@ -72,21 +74,15 @@ fn is_pid_valid(input: &str) -> bool {
     input.len() == 9 && input.chars().all(|c| c.is_ascii_digit())
 }
 
-fn parse(input: &str) -> Batch {
+fn parse(input: &Input) -> Batch<'_> {
     input
-        .split("\n\n")
-        .map(|passport| {
-            Passport(
-                passport
-                    .split_whitespace()
-                    .map(|field| field.split_once(':').unwrap())
-                    .collect(),
-            )
-        })
+        .records()
+        .into_iter()
+        .map(|fields| Passport(fields.into_iter().collect()))
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<String> {
+pub fn part_one(input: &Input) -> Option<String> {
     parse(input)
         .iter()
         .filter(|p| p.is_valid_1())
@@ -95,7 +91,7 @@ pub fn part_one(input: &str) -> Option<String> {
         .into()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &Input) -> Option<String> {
     parse(input)
         .iter()
         .filter(|p| p.is_valid_2())
@@ -126,7 +122,7 @@ mod tests {
             hcl:#cfa07d eyr:2025 pid:166559648
             iyr:2011 ecl:brn hgt:59in
         "};
-        let result = part_one(INPUT).unwrap();
+        let result = part_one(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "2");
     }
 
@@ -147,7 +143,7 @@ mod tests {
             hcl:#cfa07d eyr:2025 pid:166559648
             iyr:2011 ecl:brn hgt:59in
         "};
-        let result = part_two(INPUT).unwrap();
+        let result = part_two(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "2");
     }
 }
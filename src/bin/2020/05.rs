@@ -1,4 +1,4 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(2020, 5);
 
 fn row(input: &str) -> usize {
     let mut low = 0;
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
-advent_of_code::solution!(6);
+use advent_of_code::Input;
 
-pub fn part_one(input: &str) -> Option<String> {
+advent_of_code::solution!(2020, 6);
+
+pub fn part_one(input: &Input) -> Option<String> {
     let mut sum = 0;
-    for group in input.split("\n\n") {
+    for group in input.groups() {
         let mut answers = HashSet::new();
         for line in group.lines() {
             for c in line.chars() {
@@ -16,10 +18,10 @@ pub fn part_one(input: &str) -> Option<String> {
     sum.to_string().into()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+pub fn part_two(input: &Input) -> Option<String> {
     const ALPHA: &str = "abcdefghijklmnopqrstuvwxyz";
     let mut sum = 0;
-    for group in input.split("\n\n") {
+    for group in input.groups() {
         let mut count = 0;
         for c in ALPHA.chars() {
             if group.lines().all(|line| line.contains(c)) {
@@ -55,7 +57,7 @@ mod tests {
 
             b
         "};
-        let result = part_one(INPUT).unwrap();
+        let result = part_one(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "11");
     }
 
@@ -78,7 +80,7 @@ mod tests {
 
             b
         "};
-        let result = part_two(INPUT).unwrap();
+        let result = part_two(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "6");
     }
 }
@@ -1,67 +1,58 @@
-use std::collections::{HashMap, HashSet};
+use advent_of_code::{Graph, NodeId};
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2020, 7);
 
-type Rule = (String, HashMap<String, usize>);
-type Rules = HashMap<String, HashMap<String, usize>>;
+/// Bags as nodes, with an edge to every bag they directly contain weighted by its quantity.
+type Rules = Graph<usize>;
 
-fn parse_rule(input: &str) -> Rule {
+fn parse_rule(rules: &mut Rules, input: &str) {
     let input = input.strip_suffix('.').unwrap();
     let (color, contents) = input.split_once(" bags contain ").unwrap();
-    let mut contents_map = HashMap::new();
+    let bag = rules.node(color);
     // 1 bright white bag, 2 muted yellow bags
-    for bag in contents.split(", ") {
-        if bag == "no other bags" {
+    for content in contents.split(", ") {
+        if content == "no other bags" {
             continue;
         }
         // 1 bright white bag
-        let (quantity, color) = bag.split_once(' ').unwrap();
+        let (quantity, color) = content.split_once(' ').unwrap();
         let quantity = quantity.parse().unwrap();
         // bright white bag
         let (color, _) = color.rsplit_once(' ').unwrap();
-        contents_map.insert(color.to_string(), quantity);
+        let inner = rules.node(color);
+        rules.add_edge(bag, inner, quantity);
     }
-    (color.to_string(), contents_map)
-}
-
-/// Find all bags containing `target`, directly or indirectly, given `rules`.
-fn find_bags_containing(rules: &Rules, target: &str) -> HashSet<String> {
-    let mut bags_containing_target = HashSet::new();
-
-    for (bag, contents) in rules.iter() {
-        if contents.contains_key(target) {
-            bags_containing_target.insert(bag.clone());
-            // find all bags containing this bag
-            bags_containing_target.extend(find_bags_containing(rules, bag));
-        }
-    }
-
-    bags_containing_target
 }
 
 /// Return the total amount of bags inside `start`.
-fn bag_depth(rules: &Rules, start: &str) -> usize {
+fn bag_depth(rules: &Rules, start: NodeId) -> usize {
     rules
-        .get(start)
-        .unwrap()
-        .iter()
-        .map(|(bag, quantity)| quantity * (1 + bag_depth(rules, bag)))
-        .sum()
+        .evaluate(start, |_, contents| {
+            contents
+                .iter()
+                .map(|&(&quantity, inside)| quantity * (1 + inside))
+                .sum()
+        })
+        .unwrap_or_else(|e| panic!("{e}"))
 }
 
 fn parse(input: &str) -> Rules {
-    input.lines().map(parse_rule).collect()
+    let mut rules = Graph::new();
+    for line in input.lines() {
+        parse_rule(&mut rules, line);
+    }
+    rules
 }
 
 pub fn part_one(input: &str) -> Option<String> {
     let rules = parse(input);
-    let bags = find_bags_containing(&rules, "shiny gold");
+    let bags = rules.ancestors(rules.id("shiny gold")?);
     bags.len().to_string().into()
 }
 
 pub fn part_two(input: &str) -> Option<String> {
     let rules = parse(input);
-    let depth = bag_depth(&rules, "shiny gold");
+    let depth = bag_depth(&rules, rules.id("shiny gold")?);
     depth.to_string().into()
 }
 
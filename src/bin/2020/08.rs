@@ -0,0 +1,98 @@
+use advent_of_code::{Halt, Instruction, Program, Vm};
+
+advent_of_code::solution!(2020, 8);
+
+fn parse(input: &str) -> Program {
+    input.parse().unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Determines if a given `program` will terminate and if so,
+/// returns the value of the accumulator when it does.
+fn does_terminate(program: &Program) -> Option<i64> {
+    let mut vm = Vm::new(program);
+    (vm.run() == Halt::Terminated).then(|| vm.acc())
+}
+
+/// Flip a JMP to a NOP and vice versa.
+fn flip_instruction(instruction: Instruction) -> Instruction {
+    match instruction {
+        Instruction::Jmp(op) => Instruction::Nop(op),
+        Instruction::Nop(op) => Instruction::Jmp(op),
+        _ => unreachable!(),
+    }
+}
+
+/// Find a corrupted instruction and return the value of the accumulator after
+/// successfully terminating.
+fn find_corrupted_instruction(program: &Program) -> Option<i64> {
+    for (pc, _) in program
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter(|(_, instr)| !matches!(instr, Instruction::Acc(_)))
+    {
+        let mut program = program.clone();
+        program[pc] = flip_instruction(program[pc]);
+        if let Some(acc) = does_terminate(&program) {
+            return Some(acc);
+        }
+    }
+    None
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    let program = parse(input);
+    let mut vm = Vm::new(&program);
+    match vm.run() {
+        Halt::Loop { .. } => vm.acc().to_string().into(),
+        _ => None,
+    }
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let program = parse(input);
+    find_corrupted_instruction(&program)
+        .unwrap()
+        .to_string()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part_one() {
+        const INPUT: &str = indoc! {"
+            nop +0
+            acc +1
+            jmp +4
+            acc +3
+            jmp -3
+            acc -99
+            acc +1
+            jmp -4
+            acc +6
+        "};
+        let result = part_one(INPUT).unwrap();
+        assert_eq!(result, "5");
+    }
+
+    #[test]
+    fn test_part_two() {
+        const INPUT: &str = indoc! {"
+            nop +0
+            acc +1
+            jmp +4
+            acc +3
+            jmp -3
+            acc -99
+            acc +1
+            jmp -4
+            acc +6
+        "};
+        let result = part_two(INPUT).unwrap();
+        assert_eq!(result, "8");
+    }
+}
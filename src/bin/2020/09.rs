@@ -1,4 +1,6 @@
-advent_of_code::solution!(9);
+use advent_of_code::Input;
+
+advent_of_code::solution!(2020, 9);
 
 struct RingBuffer {
     capacity: usize,
@@ -23,10 +25,6 @@ impl From<&[u64]> for RingBuffer {
     }
 }
 
-fn parse(input: &str) -> Vec<u64> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
-}
-
 /// Determine if a pair of integers in `numbers` exists that sum to `target_sum`.
 pub fn has_sum_pair(numbers: &[u64], target_sum: u64) -> bool {
     for i in 0..(numbers.len() - 1) {
@@ -86,13 +84,13 @@ fn find_weakness_2(numbers: &[u64], target_sum: u64) -> Option<u64> {
     None
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let numbers = parse(input);
+pub fn part_one(input: &Input) -> Option<String> {
+    let numbers: Vec<u64> = input.ints();
     find_weakness_1(&numbers, 25).unwrap().to_string().into()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let numbers = parse(input);
+pub fn part_two(input: &Input) -> Option<String> {
+    let numbers: Vec<u64> = input.ints();
     let target_sum = find_weakness_1(&numbers, 25).unwrap();
     find_weakness_2(&numbers, target_sum)
         .unwrap()
@@ -158,7 +156,7 @@ mod tests {
             309
             576
         "};
-        let numbers = parse(INPUT);
+        let numbers: Vec<u64> = Input::from(INPUT).ints();
         let result = find_weakness_1(&numbers, 5).unwrap();
         assert_eq!(result, 127);
     }
@@ -187,7 +185,7 @@ mod tests {
             309
             576
         "};
-        let numbers = parse(INPUT);
+        let numbers: Vec<u64> = Input::from(INPUT).ints();
         let result = find_weakness_2(&numbers, 127).unwrap();
         assert_eq!(result, 62);
     }
@@ -1,11 +1,9 @@
-advent_of_code::solution!(10);
+use advent_of_code::Input;
 
-fn parse(input: &str) -> Vec<u8> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
-}
+advent_of_code::solution!(2020, 10);
 
-pub fn part_one(input: &str) -> Option<String> {
-    let mut adapters = parse(input);
+pub fn part_one(input: &Input) -> Option<String> {
+    let mut adapters: Vec<u8> = input.ints();
     adapters.sort();
     // charging outlet
     adapters.insert(0, 0);
@@ -32,8 +30,8 @@ pub fn part_one(input: &str) -> Option<String> {
     (one_jolt_diff * three_jolt_diff).to_string().into()
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut adapters = parse(input);
+pub fn part_two(input: &Input) -> Option<String> {
+    let mut adapters: Vec<u8> = input.ints();
     adapters.sort();
     // charging outlet
     adapters.insert(0, 0);
@@ -95,7 +93,7 @@ mod tests {
             10
             3
         "};
-        let result = part_one(INPUT).unwrap();
+        let result = part_one(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "220");
     }
 
@@ -134,7 +132,7 @@ mod tests {
             10
             3
         "};
-        let result = part_two(INPUT).unwrap();
+        let result = part_two(&Input::from(INPUT)).unwrap();
         assert_eq!(result, "19208");
     }
 }
@@ -0,0 +1,114 @@
+use advent_of_code::{Automaton, Grid, Neighbourhood};
+
+advent_of_code::solution!(2020, 11);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl TryFrom<char> for Seat {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Seat::Floor),
+            'L' => Ok(Seat::Empty),
+            '#' => Ok(Seat::Occupied),
+            c => Err(c),
+        }
+    }
+}
+
+type SeatLayout = Grid<Seat>;
+
+fn parse(input: &str) -> SeatLayout {
+    input.parse().unwrap()
+}
+
+fn count_occupied_seats(seat_layout: &SeatLayout) -> usize {
+    seat_layout
+        .values()
+        .filter(|&&seat| seat == Seat::Occupied)
+        .count()
+}
+
+fn count_occupied<'a>(seats: impl Iterator<Item = &'a Seat>) -> usize {
+    seats.filter(|&&seat| seat == Seat::Occupied).count()
+}
+
+/// Simulates seating until no seat changes. Seats become empty once `tolerance` of the seats considered
+/// by `occupied_neighbours` are occupied.
+fn simulate_seating(
+    seat_layout: &SeatLayout,
+    occupied_neighbours: impl Fn(Neighbourhood<'_, Seat>) -> usize,
+    tolerance: usize,
+) -> usize {
+    let mut automaton = Automaton::new(seat_layout.clone(), |n| match n.cell() {
+        Seat::Empty if occupied_neighbours(n) == 0 => Seat::Occupied,
+        Seat::Occupied if occupied_neighbours(n) >= tolerance => Seat::Empty,
+        &seat => seat,
+    });
+
+    count_occupied_seats(automaton.run_until_stable())
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    let seat_layout = parse(input);
+    let occupied_seats = simulate_seating(&seat_layout, |n| count_occupied(n.adjacent()), 4);
+    occupied_seats.to_string().into()
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let seat_layout = parse(input);
+    let occupied_seats = simulate_seating(
+        &seat_layout,
+        |n| count_occupied(n.visible(|&seat| seat == Seat::Floor)),
+        5,
+    );
+    occupied_seats.to_string().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part_one() {
+        const INPUT: &str = indoc! {"
+            L.LL.LL.LL
+            LLLLLLL.LL
+            L.L.L..L..
+            LLLL.LL.LL
+            L.LL.LL.LL
+            L.LLLLL.LL
+            ..L.L.....
+            LLLLLLLLLL
+            L.LLLLLL.L
+            L.LLLLL.LL
+        "};
+        let result = part_one(INPUT).unwrap();
+        assert_eq!(result, "37");
+    }
+
+    #[test]
+    fn test_part_two() {
+        const INPUT: &str = indoc! {"
+            L.LL.LL.LL
+            LLLLLLL.LL
+            L.L.L..L..
+            LLLL.LL.LL
+            L.LL.LL.LL
+            L.LLLLL.LL
+            ..L.L.....
+            LLLLLLLLLL
+            L.LLLLLL.L
+            L.LLLLL.LL
+        "};
+        let result = part_two(INPUT).unwrap();
+        assert_eq!(result, "26");
+    }
+}
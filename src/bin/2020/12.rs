@@ -0,0 +1,90 @@
+use advent_of_code::{Direction, Vec2};
+
+advent_of_code::solution!(2020, 12);
+
+enum Instruction {
+    Move(Direction, i32),
+    Left(i32),
+    Right(i32),
+    Forward(i32),
+}
+
+fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            let (inst, value) = line.split_at(1);
+            let value = value.parse().unwrap();
+            match inst {
+                "L" => Instruction::Left(value),
+                "R" => Instruction::Right(value),
+                "F" => Instruction::Forward(value),
+                direction => Instruction::Move(direction.parse().unwrap(), value),
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    let mut ship = Vec2::ZERO;
+    let mut heading = Direction::East;
+
+    for inst in parse(input) {
+        match inst {
+            Instruction::Move(direction, value) => ship += direction.offset() * value,
+            Instruction::Left(angle) => heading = heading.turn_left(angle)?,
+            Instruction::Right(angle) => heading = heading.turn_right(angle)?,
+            Instruction::Forward(value) => ship += heading.offset() * value,
+        }
+    }
+
+    ship.manhattan().to_string().into()
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let mut ship = Vec2::ZERO;
+    let mut waypoint = Vec2::new(10, 1);
+
+    for inst in parse(input) {
+        match inst {
+            Instruction::Move(direction, value) => waypoint += direction.offset() * value,
+            Instruction::Left(angle) => waypoint = waypoint.rotate_left(angle)?,
+            Instruction::Right(angle) => waypoint = waypoint.rotate_right(angle)?,
+            Instruction::Forward(value) => ship += waypoint * value,
+        }
+    }
+
+    ship.manhattan().to_string().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part_one() {
+        const INPUT: &str = indoc! {"
+            F10
+            N3
+            F7
+            R90
+            F11
+        "};
+        let result = part_one(INPUT).unwrap();
+        assert_eq!(result, "25");
+    }
+
+    #[test]
+    fn test_part_two() {
+        const INPUT: &str = indoc! {"
+            F10
+            N3
+            F7
+            R90
+            F11
+        "};
+        let result = part_two(INPUT).unwrap();
+        assert_eq!(result, "286");
+    }
+}
@@ -0,0 +1,202 @@
+use crate::template::datetime;
+use crate::{AllDays, Day, Part, Year};
+
+/// The highest day number any calendar may have.
+pub const MAX_DAYS: u8 = 31;
+
+/// Describes when the puzzles of a calendar unlock.
+/// The puzzle for day `n` unlocks on day `n` of `month` at `hour`, in a timezone `utc_offset` seconds from UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unlock {
+    pub month: u8,
+    pub hour: u8,
+    pub utc_offset: i64,
+}
+
+/// The calendar of an event, i.e. how many days it has, how many parts each day has and when puzzles unlock.
+/// Calendars are created with [`Calendar::new`], which validates the number of days and parts.
+///
+/// ```
+/// # use advent_of_code::{Calendar, year};
+/// let calendar = Calendar::for_year(year!(2020));
+/// assert_eq!(calendar.days().count(), 25);
+/// assert!(calendar.day(26).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calendar {
+    /// The number of days, counting from 1.
+    days: u8,
+    /// The number of parts of each day except the last one.
+    parts_per_day: u8,
+    /// The number of parts of the last day.
+    final_day_parts: u8,
+    unlock: Unlock,
+}
+
+impl Calendar {
+    /// The calendar of advent of code until 2024: 25 days with two parts each, except for the last day.
+    /// Puzzles unlock at midnight EST (UTC-5).
+    pub const ADVENT_OF_CODE: Calendar = Calendar::new(
+        25,
+        2,
+        1,
+        Unlock {
+            month: 12,
+            hour: 0,
+            utc_offset: -5 * 60 * 60,
+        },
+    );
+
+    /// The calendar of advent of code from 2025 onwards, which was shortened to 12 days.
+    pub const ADVENT_OF_CODE_2025: Calendar =
+        Calendar::new(12, 2, 1, Calendar::ADVENT_OF_CODE.unlock);
+
+    /// Creates a [`Calendar`]. Panics (or fails to compile in a const context) if `days` is not
+    /// between 1 and [`MAX_DAYS`] or a day would have no or more than two parts.
+    pub const fn new(days: u8, parts_per_day: u8, final_day_parts: u8, unlock: Unlock) -> Self {
+        assert!(days > 0 && days <= MAX_DAYS, "invalid number of days");
+        assert!(
+            parts_per_day > 0 && parts_per_day <= 2,
+            "invalid number of parts"
+        );
+        assert!(
+            final_day_parts > 0 && final_day_parts <= 2,
+            "invalid number of parts"
+        );
+        Self {
+            days,
+            parts_per_day,
+            final_day_parts,
+            unlock,
+        }
+    }
+
+    /// Returns the advent of code calendar of `year`.
+    pub fn for_year(year: Year) -> Self {
+        if year >= 2025 {
+            Self::ADVENT_OF_CODE_2025
+        } else {
+            Self::ADVENT_OF_CODE
+        }
+    }
+
+    /// Returns the number of days of this calendar.
+    pub const fn day_count(&self) -> u8 {
+        self.days
+    }
+
+    /// Returns the number of parts of each day except the last one.
+    pub const fn parts_per_day(&self) -> u8 {
+        self.parts_per_day
+    }
+
+    /// Returns the number of parts of the last day.
+    pub const fn final_day_parts(&self) -> u8 {
+        self.final_day_parts
+    }
+
+    /// Returns when the puzzles of this calendar unlock.
+    pub const fn unlock(&self) -> Unlock {
+        self.unlock
+    }
+
+    /// Creates a [`Day`] from the provided value if it's part of this calendar,
+    /// returns [`None`] otherwise.
+    pub fn day(&self, day: u8) -> Option<Day> {
+        if day == 0 || day > self.days {
+            return None;
+        }
+        Some(Day::__new_unchecked(day))
+    }
+
+    /// Returns whether `day` is part of this calendar.
+    pub fn contains(&self, day: Day) -> bool {
+        day <= self.days
+    }
+
+    /// An iterator that yields every day of this calendar.
+    pub fn days(&self) -> AllDays {
+        AllDays::until(self.days)
+    }
+
+    /// Returns the day of the event of `year` that is running at `timestamp`,
+    /// i.e. the last day that unlocked less than 24 hours before.
+    /// Returns [`None`] before the first and after the last day of the event.
+    pub fn day_at(&self, year: Year, timestamp: i64) -> Option<Day> {
+        self.days()
+            .take_while(|&day| datetime::unlock_timestamp(self, year, day) <= timestamp)
+            .last()
+            .filter(|&day| timestamp - datetime::unlock_timestamp(self, year, day) < 24 * 60 * 60)
+    }
+
+    /// Returns the number of parts of `day`.
+    pub fn parts(&self, day: Day) -> u8 {
+        match day {
+            d if !self.contains(d) => 0,
+            d if d == self.days => self.final_day_parts,
+            _ => self.parts_per_day,
+        }
+    }
+
+    /// Returns whether `day` has a puzzle `part` that can be solved.
+    pub fn has_part(&self, day: Day, part: Part) -> bool {
+        part.into_inner() <= self.parts(day)
+    }
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self::ADVENT_OF_CODE
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Calendar;
+    use crate::{day, year, Part};
+
+    #[test]
+    fn selects_calendar_for_year() {
+        assert_eq!(Calendar::for_year(year!(2020)).day_count(), 25);
+        assert_eq!(Calendar::for_year(year!(2025)).day_count(), 12);
+        assert_eq!(
+            Calendar::ADVENT_OF_CODE_2025.unlock(),
+            Calendar::ADVENT_OF_CODE.unlock()
+        );
+    }
+
+    #[test]
+    fn finds_day_at_timestamp() {
+        let calendar = Calendar::ADVENT_OF_CODE;
+        // 2020-12-01 00:00:00 EST
+        let unlock = 1_606_798_800;
+        assert_eq!(calendar.day_at(year!(2020), unlock - 1), None);
+        assert_eq!(calendar.day_at(year!(2020), unlock), Some(day!(1)));
+        assert_eq!(calendar.day_at(year!(2020), unlock + 86_399), Some(day!(1)));
+        assert_eq!(calendar.day_at(year!(2020), unlock + 86_400), Some(day!(2)));
+        assert_eq!(
+            calendar.day_at(year!(2020), unlock + 24 * 86_400 + 3600),
+            Some(day!(25))
+        );
+        assert_eq!(calendar.day_at(year!(2020), unlock + 25 * 86_400), None);
+        assert_eq!(calendar.day_at(year!(2021), unlock), None);
+    }
+
+    #[test]
+    fn validates_days_and_parts() {
+        let calendar = Calendar::ADVENT_OF_CODE_2025;
+        assert_eq!(
+            calendar.day(12),
+            Some(day!(12, Calendar::ADVENT_OF_CODE_2025))
+        );
+        assert_eq!(calendar.day(13), None);
+        assert_eq!(calendar.days().last(), Some(day!(12)));
+        assert!(calendar.has_part(day!(11), Part::Two));
+        assert!(!calendar.has_part(day!(12), Part::Two));
+        assert!(!calendar.has_part(day!(13), Part::One));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Grid;
+
+/// A point in `N`-dimensional integer space.
+pub type Point<const N: usize> = [i32; N];
+
+/// Returns the offsets of all `3^N - 1` neighbours of a point, including diagonal ones.
+#[must_use]
+pub fn neighbour_offsets<const N: usize>() -> Vec<Point<N>> {
+    let count = 3_usize.pow(u32::try_from(N).expect("too many dimensions"));
+
+    (0..count)
+        .map(|mut i| {
+            let mut offset = [0; N];
+            for axis in &mut offset {
+                // digits of `i` in base 3, shifted to -1..=1.
+                *axis = i32::try_from(i % 3).unwrap() - 1;
+                i /= 3;
+            }
+            offset
+        })
+        .filter(|offset| offset.iter().any(|&axis| axis != 0))
+        .collect()
+}
+
+/// A sparse Conway-style automaton in `N` dimensions, storing only the active points.
+/// As space is unbounded, the active region may grow without limit.
+///
+/// ```
+/// # use advent_of_code::{Conway, Grid};
+/// let grid = Grid::parse(".#.\n..#\n###", |c| Some(c == '#')).unwrap();
+/// let mut cubes = Conway::<3>::from_grid(&grid, |&active| active);
+/// cubes.run(6);
+/// assert_eq!(cubes.len(), 112);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conway<const N: usize> {
+    active: HashSet<Point<N>>,
+    offsets: Vec<Point<N>>,
+    generation: usize,
+}
+
+impl<const N: usize> Conway<N> {
+    /// Creates an automaton with the given active points.
+    pub fn new(active: impl IntoIterator<Item = Point<N>>) -> Self {
+        Self::with_neighbours(active, neighbour_offsets())
+    }
+
+    /// Creates an automaton with the given active points, where the neighbours of a point are at `offsets`
+    /// instead of all adjacent points, e.g. for hexagonal or orthogonal neighbourhoods.
+    pub fn with_neighbours(
+        active: impl IntoIterator<Item = Point<N>>,
+        offsets: impl IntoIterator<Item = Point<N>>,
+    ) -> Self {
+        Self {
+            active: active.into_iter().collect(),
+            offsets: offsets.into_iter().collect(),
+            generation: 0,
+        }
+    }
+
+    /// Creates an automaton from a 2D slice, placing the cells of `grid` at `(x, y, 0, …)`.
+    ///
+    /// # Panics
+    /// Panics if `N` is less than two.
+    pub fn from_grid<T>(grid: &Grid<T>, is_active: impl Fn(&T) -> bool) -> Self {
+        assert!(N >= 2, "a grid needs at least two dimensions");
+
+        Self::new(
+            grid.iter()
+                .filter(|(_, cell)| is_active(cell))
+                .map(|((x, y), _)| {
+                    let mut point = [0; N];
+                    point[0] = i32::try_from(x).expect("grid is too wide");
+                    point[1] = i32::try_from(y).expect("grid is too high");
+                    point
+                }),
+        )
+    }
+
+    /// The number of active points.
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        self.active.contains(point)
+    }
+
+    /// Returns the active points, in no particular order.
+    pub fn active(&self) -> impl Iterator<Item = &Point<N>> {
+        self.active.iter()
+    }
+
+    /// The number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Returns the smallest and largest coordinate of the active points along every axis,
+    /// or [`None`] if no point is active.
+    pub fn bounds(&self) -> Option<(Point<N>, Point<N>)> {
+        let mut points = self.active.iter();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(mut min, mut max), point| {
+            for axis in 0..N {
+                min[axis] = min[axis].min(point[axis]);
+                max[axis] = max[axis].max(point[axis]);
+            }
+            (min, max)
+        }))
+    }
+
+    /// Counts the active neighbours of every point that has at least one.
+    pub fn neighbour_counts(&self) -> HashMap<Point<N>, usize> {
+        let mut counts = HashMap::with_capacity(self.active.len() * self.offsets.len());
+
+        for point in &self.active {
+            for offset in &self.offsets {
+                let mut neighbour = *point;
+                for axis in 0..N {
+                    neighbour[axis] += offset[axis];
+                }
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        counts
+    }
+
+    /// Computes the next generation, where `rule` decides from whether a point is active
+    /// and its number of active neighbours whether it is active in the next generation.
+    /// Points without active neighbours are always inactive in the next generation.
+    pub fn step_with(&mut self, rule: impl Fn(bool, usize) -> bool) {
+        self.active = self
+            .neighbour_counts()
+            .into_iter()
+            .filter(|(point, count)| rule(self.active.contains(point), *count))
+            .map(|(point, _)| point)
+            .collect();
+        self.generation += 1;
+    }
+
+    /// Computes the next generation with the rules of the game of life:
+    /// active points with two or three active neighbours stay active, inactive points with three become active.
+    pub fn step(&mut self) {
+        self.step_with(|active, count| count == 3 || (active && count == 2));
+    }
+
+    /// Computes `generations` generations with [`Conway::step`].
+    pub fn run(&mut self, generations: usize) -> usize {
+        for _ in 0..generations {
+            self.step();
+        }
+        self.len()
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{neighbour_offsets, Conway};
+    use crate::Grid;
+
+    fn example() -> Grid<bool> {
+        Grid::parse(".#.\n..#\n###", |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn computes_neighbour_offsets() {
+        assert_eq!(neighbour_offsets::<1>(), [[-1], [1]]);
+        assert_eq!(neighbour_offsets::<2>().len(), 8);
+        assert_eq!(neighbour_offsets::<3>().len(), 26);
+        assert_eq!(neighbour_offsets::<4>().len(), 80);
+    }
+
+    #[test]
+    fn simulates_three_dimensions() {
+        let mut cubes = Conway::<3>::from_grid(&example(), |&active| active);
+        assert_eq!(cubes.len(), 5);
+
+        cubes.step();
+        assert_eq!(cubes.len(), 11);
+        assert_eq!(cubes.bounds(), Some(([0, 1, -1], [2, 3, 1])));
+
+        assert_eq!(cubes.run(5), 112);
+        assert_eq!(cubes.generation(), 6);
+    }
+
+    #[test]
+    fn simulates_four_dimensions() {
+        let mut cubes = Conway::<4>::from_grid(&example(), |&active| active);
+        assert_eq!(cubes.run(6), 848);
+    }
+
+    #[test]
+    fn matches_two_dimensional_life() {
+        let mut blinker = Conway::<2>::new([[0, 0], [1, 0], [2, 0]]);
+        blinker.step();
+        assert!(blinker.contains(&[1, -1]) && blinker.contains(&[1, 1]));
+        blinker.step();
+        let mut points: Vec<_> = blinker.active().copied().collect();
+        points.sort_unstable();
+        assert_eq!(points, [[0, 0], [1, 0], [2, 0]]);
+
+        // active points only count towards the points to their right and below.
+        let mut custom = Conway::<2>::with_neighbours([[0, 0], [1, -1]], [[1, 0], [0, 1]]);
+        custom.step_with(|_, count| count == 2);
+        assert_eq!(custom.active().collect::<Vec<_>>(), [&[1, 0]]);
+
+        let mut dying = Conway::<2>::new([[0, 0]]);
+        dying.step();
+        assert!(dying.is_empty());
+        assert_eq!(dying.bounds(), None);
+    }
+}
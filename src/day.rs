@@ -2,7 +2,12 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+use crate::template::datetime;
+use crate::{Calendar, Year};
+
+/// A valid day number of advent.
+/// [`Day::new`] accepts the days of the default advent of code calendar (i.e. an integer in range 1 to 25),
+/// use [`Calendar::day`] to validate against other calendars.
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -19,10 +24,13 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 25 {
-            return None;
-        }
-        Some(Self(day))
+        Calendar::ADVENT_OF_CODE.day(day)
+    }
+
+    /// Returns the current day of the event of `year` in the timezone of the event,
+    /// returns [`None`] if the event is not running.
+    pub fn today_of(year: Year) -> Option<Self> {
+        Calendar::for_year(year).day_at(year, datetime::now_timestamp())
     }
 
     // Not part of the public API
@@ -68,6 +76,7 @@ impl FromStr for Day {
 
 /// An error which can be returned when parsing a [`Day`].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DayFromStrError;
 
 impl Error for DayFromStrError {}
@@ -80,20 +89,41 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// Days serialize as their number.
+/// Deserialization accepts every day a [`Calendar`] can have, i.e. 1 to [`MAX_DAYS`](crate::MAX_DAYS).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Day {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Day {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let day = u8::deserialize(deserializer)?;
+        if day == 0 || day > crate::MAX_DAYS {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(u64::from(day)),
+                &"a day number between 1 and 31",
+            ));
+        }
+        Ok(Self(day))
+    }
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every day of a calendar, see [`Calendar::days`].
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    /// Creates an iterator that yields every day from the 1st to `last`.
+    pub(crate) fn until(last: u8) -> Self {
+        Self { current: 1, last }
     }
 }
 
@@ -101,10 +131,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and `last` is the number of days of a valid calendar.
         let day = Day(self.current);
         self.current += 1;
 
@@ -115,6 +145,7 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// The day is validated against the default advent of code calendar, or the [`Calendar`] passed as second argument.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
@@ -128,17 +159,31 @@ macro_rules! day {
         );
         $crate::Day::__new_unchecked($day)
     }};
+    ($day:expr, $calendar:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $calendar.day_count(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, expecting a day of calendar `",
+                stringify!($calendar),
+                "`"
+            ),
+        );
+        $crate::Day::__new_unchecked($day)
+    }};
 }
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::Day;
+    use crate::Calendar;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = Calendar::ADVENT_OF_CODE.days();
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -167,6 +212,15 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serializes_as_number() {
+        assert_eq!(serde_json::to_string(&Day(8)).unwrap(), "8");
+        assert_eq!(serde_json::from_str::<Day>("8").unwrap(), Day(8));
+        assert!(serde_json::from_str::<Day>("0").is_err());
+        assert!(serde_json::from_str::<Day>("\"08\"").is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::vec2::quarter_turns;
+use crate::Vec2;
+
+/// A cardinal direction on the plane of [`Vec2`].
+///
+/// # Display
+/// This value displays as its initial, e.g. `N`.
+///
+/// ```
+/// # use advent_of_code::{Direction, Vec2};
+/// let heading = Direction::East.turn_right(90).unwrap();
+/// assert_eq!(heading, Direction::South);
+/// assert_eq!(heading.offset() * 3, Vec2::new(0, -3));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns a step of length one in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(0, 1),
+            Direction::East => Vec2::new(1, 0),
+            Direction::South => Vec2::new(0, -1),
+            Direction::West => Vec2::new(-1, 0),
+        }
+    }
+
+    /// Turns by `quarters` quarter turns, counterclockwise for positive values.
+    #[must_use]
+    pub fn turn_quarters(self, quarters: i32) -> Direction {
+        // `ALL` is ordered clockwise, so counterclockwise turns move backwards.
+        Direction::ALL[(self as i32 - quarters).rem_euclid(4) as usize]
+    }
+
+    /// Turns counterclockwise by `degrees`, returns [`None`] if `degrees` is not a multiple of 90°.
+    #[must_use]
+    pub fn turn_left(self, degrees: i32) -> Option<Direction> {
+        quarter_turns(degrees).map(|quarters| self.turn_quarters(quarters))
+    }
+
+    /// Turns clockwise by `degrees`, returns [`None`] if `degrees` is not a multiple of 90°.
+    #[must_use]
+    pub fn turn_right(self, degrees: i32) -> Option<Direction> {
+        quarter_turns(degrees).map(|quarters| self.turn_quarters(-quarters))
+    }
+
+    /// Returns the opposite direction.
+    #[must_use]
+    pub fn reverse(self) -> Direction {
+        self.turn_quarters(2)
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Direction::North => "N",
+            Direction::East => "E",
+            Direction::South => "S",
+            Direction::West => "W",
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Direction {
+    type Err = DirectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.to_string() == s)
+            .ok_or(DirectionFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction`].
+#[derive(Debug)]
+pub struct DirectionFromStrError;
+
+impl Error for DirectionFromStrError {}
+
+impl Display for DirectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a direction (N, E, S, W)")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Direction;
+
+    #[test]
+    fn turns_in_both_directions() {
+        assert_eq!(Direction::East.turn_left(90), Some(Direction::North));
+        assert_eq!(Direction::East.turn_right(90), Some(Direction::South));
+        assert_eq!(Direction::North.turn_left(270), Some(Direction::East));
+        assert_eq!(Direction::North.turn_right(-90), Some(Direction::West));
+        assert_eq!(Direction::West.turn_right(450), Some(Direction::North));
+        assert_eq!(Direction::West.turn_left(30), None);
+        assert_eq!(Direction::South.reverse(), Direction::North);
+    }
+
+    #[test]
+    fn matches_vector_rotation() {
+        for direction in Direction::ALL {
+            for quarters in -5..=5 {
+                assert_eq!(
+                    direction.turn_quarters(quarters).offset(),
+                    direction.offset().rotate_quarters(quarters)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_directions() {
+        assert_eq!("N".parse::<Direction>().unwrap(), Direction::North);
+        assert_eq!(Direction::West.to_string(), "W");
+        assert!("F".parse::<Direction>().is_err());
+    }
+}
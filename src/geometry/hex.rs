@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub};
+use std::str::FromStr;
+
+use crate::{Conway, Point};
+
+/// A step between neighbouring hexagons of a grid of "pointy-top" hexagons, i.e. hexagons with
+/// neighbours to the east and west, but not to the north and south.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    /// All directions, clockwise starting east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Returns the offset of the neighbour in this direction.
+    pub fn offset(self) -> Hex {
+        let (q, r) = match self {
+            HexDirection::East => (1, 0),
+            HexDirection::SouthEast => (0, 1),
+            HexDirection::SouthWest => (-1, 1),
+            HexDirection::West => (-1, 0),
+            HexDirection::NorthWest => (0, -1),
+            HexDirection::NorthEast => (1, -1),
+        };
+        Hex::new(q, r)
+    }
+
+    /// Parses a path of directions without delimiters, e.g. `nwwswee`.
+    pub fn parse_path(s: &str) -> Result<Vec<HexDirection>, HexDirectionError> {
+        let mut directions = vec![];
+        let mut position = 0;
+
+        while position < s.len() {
+            let rest = &s[position..];
+            let len = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+            let direction = rest
+                .get(..len)
+                .and_then(|token| token.parse().ok())
+                .ok_or(HexDirectionError { position })?;
+
+            directions.push(direction);
+            position += len;
+        }
+
+        Ok(directions)
+    }
+}
+
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            HexDirection::East => "e",
+            HexDirection::SouthEast => "se",
+            HexDirection::SouthWest => "sw",
+            HexDirection::West => "w",
+            HexDirection::NorthWest => "nw",
+            HexDirection::NorthEast => "ne",
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A hexagon in axial coordinates, where the third cube coordinate `s` is implied by `q + r + s = 0`.
+///
+/// ```
+/// # use advent_of_code::{Hex, HexDirection};
+/// let hex: Hex = "esew".parse().unwrap();
+/// assert_eq!(hex, Hex::ORIGIN + HexDirection::SouthEast.offset());
+/// assert_eq!("nwwswee".parse::<Hex>().unwrap(), Hex::ORIGIN);
+/// assert_eq!(Hex::new(3, -1).distance(Hex::ORIGIN), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// The third cube coordinate.
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    /// Returns the neighbour in `direction`.
+    pub fn neighbour(self, direction: HexDirection) -> Hex {
+        self + direction.offset()
+    }
+
+    /// Returns the six neighbours, clockwise starting east.
+    pub fn neighbours(self) -> impl Iterator<Item = Hex> {
+        HexDirection::ALL
+            .into_iter()
+            .map(move |direction| self.neighbour(direction))
+    }
+
+    /// Returns the number of steps between two hexagons.
+    pub fn distance(self, other: Hex) -> u32 {
+        let d = self - other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s().unsigned_abs()) / 2
+    }
+
+    /// Returns the hexagon reached by following `path` from this one.
+    pub fn walk(self, path: impl IntoIterator<Item = HexDirection>) -> Hex {
+        path.into_iter().fold(self, Hex::neighbour)
+    }
+
+    /// Converts to a point for the [`Conway`] automaton.
+    pub fn to_point(self) -> Point<2> {
+        [self.q, self.r]
+    }
+
+    pub fn from_point([q, r]: Point<2>) -> Self {
+        Self { q, r }
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, rhs: Hex) -> Self::Output {
+        Hex::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, rhs: Hex) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, rhs: Hex) -> Self::Output {
+        Hex::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl Conway<2> {
+    /// Creates an automaton on a hexagonal grid, where every hexagon has six neighbours.
+    pub fn hex(active: impl IntoIterator<Item = Hex>) -> Self {
+        Self::with_neighbours(
+            active.into_iter().map(Hex::to_point),
+            HexDirection::ALL.map(|direction| direction.offset().to_point()),
+        )
+    }
+
+    /// Returns the active points as hexagons, see [`Conway::hex`].
+    pub fn hexes(&self) -> impl Iterator<Item = Hex> + '_ {
+        self.active().map(|&point| Hex::from_point(point))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for HexDirection {
+    type Err = HexDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HexDirection::ALL
+            .into_iter()
+            .find(|direction| direction.to_string() == s)
+            .ok_or(HexDirectionError { position: 0 })
+    }
+}
+
+/// Parses a path of directions (see [`HexDirection::parse_path`]) and follows it from the origin.
+impl FromStr for Hex {
+    type Err = HexDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Hex::ORIGIN.walk(HexDirection::parse_path(s)?))
+    }
+}
+
+/// An error which can be returned when parsing a [`HexDirection`] or a path of them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HexDirectionError {
+    /// The byte offset of the invalid direction.
+    pub position: usize,
+}
+
+impl Error for HexDirectionError {}
+
+impl Display for HexDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a hex direction (e, se, sw, w, nw, ne) at position {}",
+            self.position
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Hex, HexDirection, HexDirectionError};
+    use crate::Conway;
+
+    #[test]
+    fn parses_paths() {
+        use HexDirection::*;
+
+        assert_eq!(
+            HexDirection::parse_path("esenee").unwrap(),
+            [East, SouthEast, NorthEast, East]
+        );
+        assert_eq!(
+            HexDirection::parse_path("enx"),
+            Err(HexDirectionError { position: 1 })
+        );
+        assert_eq!(
+            HexDirection::parse_path("ew?"),
+            Err(HexDirectionError { position: 2 })
+        );
+        assert!("n".parse::<HexDirection>().is_err());
+        assert_eq!("nwwswee".parse::<Hex>().unwrap(), Hex::ORIGIN);
+    }
+
+    #[test]
+    fn computes_distances() {
+        for direction in HexDirection::ALL {
+            assert_eq!(Hex::ORIGIN.neighbour(direction).distance(Hex::ORIGIN), 1);
+        }
+
+        let hex = Hex::new(2, -5);
+        assert_eq!(hex.s(), 3);
+        assert_eq!(hex.distance(Hex::ORIGIN), 5);
+        assert_eq!(hex.distance(hex), 0);
+        assert!(hex.neighbours().all(|n| n.distance(hex) == 1));
+    }
+
+    #[test]
+    fn flips_tiles() {
+        let input = "sesenwnenenewseeswwswswwnenewsewsw\nneeenesenwnwwswnenewnwwsewnenwseswesw\nseswneswswsenwwnwse\nnwnwneseeswswnenewneswwnewseswneseene\nswweswneswnenwsewnwneneseenw\neesenwseswswnenwswnwnwsewwnwsene\nsewnenenenesenwsewnenwwwse\nwenwwweseeeweswwwnwwe\nwsweesenenewnwwnwsenewsenwwsesesenwne\nneeswseenwwswnwswswnw\nnenwswwsewswnenenewsenwsenwnesesenew\nenewnwewneswsewnwswenweswnenwsenwsw\nsweneswneswneneenwnewenewwneswswnese\nswwesenesewenwneswnwwneseswwne\nenesenwswwswneneswsenwnewswseenwsese\nwnwnesenesenenwwnenwsewesewsesesew\nnenewswnwewswnenesenwnesewesw\neneswnwswnwsenenwnwnwwseeswneewsenese\nneswnwewnwnwseenwseesewsenwsweewe\nwseweeenwnesenwwwswnew";
+
+        let mut black = std::collections::HashSet::new();
+        for line in input.lines() {
+            let hex: Hex = line.parse().unwrap();
+            if !black.insert(hex) {
+                black.remove(&hex);
+            }
+        }
+        assert_eq!(black.len(), 10);
+
+        let mut tiles = Conway::hex(black);
+        tiles.step_with(|black, count| count == 2 || (black && count == 1));
+        assert_eq!(tiles.len(), 15);
+        for _ in 1..10 {
+            tiles.step_with(|black, count| count == 2 || (black && count == 1));
+        }
+        assert_eq!(tiles.len(), 37);
+        assert!(tiles.hexes().all(|hex| tiles.contains(&hex.to_point())));
+    }
+}
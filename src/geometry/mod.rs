@@ -0,0 +1,7 @@
+mod direction;
+mod hex;
+mod vec2;
+
+pub use direction::*;
+pub use hex::*;
+pub use vec2::*;
@@ -0,0 +1,157 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point or displacement on a 2D integer plane, where `x` grows eastwards and `y` grows northwards.
+///
+/// # Display
+/// This value displays as `x,y`.
+///
+/// ```
+/// # use advent_of_code::Vec2;
+/// let waypoint = Vec2::new(10, 4);
+/// assert_eq!(waypoint.rotate_right(90), Some(Vec2::new(4, -10)));
+/// assert_eq!(waypoint.rotate_left(-90), Some(Vec2::new(4, -10)));
+/// assert_eq!((waypoint * 3).manhattan(), 42);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Converts an angle in degrees to quarter turns, returns [`None`] if it is not a multiple of 90°.
+pub(crate) fn quarter_turns(degrees: i32) -> Option<i32> {
+    (degrees % 90 == 0).then_some(degrees / 90)
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The Manhattan length, i.e. the distance from the origin when moving along the axes.
+    pub fn manhattan(self) -> u32 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// The Manhattan distance to `other`.
+    pub fn manhattan_distance(self, other: Vec2) -> u32 {
+        (self - other).manhattan()
+    }
+
+    /// Rotates around the origin by `quarters` quarter turns, counterclockwise for positive values.
+    #[must_use]
+    pub fn rotate_quarters(self, quarters: i32) -> Vec2 {
+        match quarters.rem_euclid(4) {
+            0 => self,
+            1 => Vec2::new(-self.y, self.x),
+            2 => -self,
+            _ => Vec2::new(self.y, -self.x),
+        }
+    }
+
+    /// Rotates around the origin counterclockwise by `degrees`, returns [`None`] if `degrees` is not a multiple of 90°.
+    #[must_use]
+    pub fn rotate_left(self, degrees: i32) -> Option<Vec2> {
+        quarter_turns(degrees).map(|quarters| self.rotate_quarters(quarters))
+    }
+
+    /// Rotates around the origin clockwise by `degrees`, returns [`None`] if `degrees` is not a multiple of 90°.
+    #[must_use]
+    pub fn rotate_right(self, degrees: i32) -> Option<Vec2> {
+        quarter_turns(degrees).map(|quarters| self.rotate_quarters(-quarters))
+    }
+}
+
+impl Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Vec2;
+
+    #[test]
+    fn computes_arithmetic() {
+        let mut a = Vec2::new(3, -4);
+        assert_eq!(a + Vec2::new(1, 1), Vec2::new(4, -3));
+        assert_eq!(a - Vec2::new(1, 1), Vec2::new(2, -5));
+        assert_eq!(-a * 2, Vec2::new(-6, 8));
+
+        a += Vec2::new(1, 0);
+        a -= Vec2::new(0, 1);
+        assert_eq!(a, Vec2::new(4, -5));
+        assert_eq!(a.to_string(), "4,-5");
+    }
+
+    #[test]
+    fn computes_manhattan_distances() {
+        assert_eq!(Vec2::new(17, -8).manhattan(), 25);
+        assert_eq!(Vec2::new(1, 1).manhattan_distance(Vec2::new(-2, 3)), 5);
+        assert_eq!(Vec2::new(i32::MIN, 0).manhattan(), 2_147_483_648);
+    }
+
+    #[test]
+    fn rotates_in_both_directions() {
+        let v = Vec2::new(10, 4);
+        assert_eq!(v.rotate_left(90), Some(Vec2::new(-4, 10)));
+        assert_eq!(v.rotate_left(180), Some(Vec2::new(-10, -4)));
+        assert_eq!(v.rotate_right(270), v.rotate_left(90));
+        assert_eq!(v.rotate_right(-90), v.rotate_left(90));
+        assert_eq!(v.rotate_left(-90), v.rotate_right(90));
+        assert_eq!(v.rotate_left(-270), Some(Vec2::new(-4, 10)));
+        assert_eq!(v.rotate_left(720), Some(v));
+        assert_eq!(v.rotate_quarters(-5), v.rotate_quarters(3));
+        assert_eq!(v.rotate_right(45), None);
+    }
+}
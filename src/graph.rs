@@ -0,0 +1,382 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Write};
+
+/// The index of a node in a [`Graph`], assigned in the order the nodes were added.
+pub type NodeId = usize;
+
+/// A directed graph with named nodes and edges weighted by `W`.
+///
+/// Node names are interned, so nodes are referred to by their [`NodeId`] after adding them.
+/// Every edge is also indexed in reverse, which makes querying predecessors as cheap as successors.
+///
+/// ```
+/// # use advent_of_code::Graph;
+/// let mut bags = Graph::new();
+/// let gold = bags.node("shiny gold");
+/// let olive = bags.node("dark olive");
+/// let blue = bags.node("faded blue");
+/// bags.add_edge(gold, olive, 1);
+/// bags.add_edge(olive, blue, 3);
+///
+/// assert_eq!(bags.ancestors(blue).len(), 2);
+/// let inside = bags.evaluate(gold, |_, contents| {
+///     contents.iter().map(|&(&count, inner)| count * (1 + inner)).sum::<usize>()
+/// });
+/// assert_eq!(inside, Ok(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = ()> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+    reverse: Vec<Vec<NodeId>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            reverse: vec![],
+        }
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the node called `name`, adding it if it does not exist yet.
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        self.reverse.push(vec![]);
+        id
+    }
+
+    /// Returns the node called `name`, or [`None`] if it was never added.
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns the name of a node.
+    ///
+    /// # Panics
+    /// Panics if the node does not exist.
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns all nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.len()
+    }
+
+    /// Adds an edge from `from` to `to`.
+    ///
+    /// # Panics
+    /// Panics if either node does not exist.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        assert!(to < self.len(), "node {to} does not exist");
+        self.edges[from].push((to, weight));
+        self.reverse[to].push(from);
+    }
+
+    /// Returns the outgoing edges of a node with their weights.
+    pub fn successors(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    /// Returns the nodes with an edge to this node, once per edge.
+    pub fn predecessors(&self, id: NodeId) -> &[NodeId] {
+        &self.reverse[id]
+    }
+
+    /// Returns all nodes reachable from `start` by following at least one edge.
+    pub fn descendants(&self, start: NodeId) -> HashSet<NodeId> {
+        Self::reachable(start, |id| self.edges[id].iter().map(|&(to, _)| to))
+    }
+
+    /// Returns all nodes from which `target` is reachable by following at least one edge.
+    pub fn ancestors(&self, target: NodeId) -> HashSet<NodeId> {
+        Self::reachable(target, |id| self.reverse[id].iter().copied())
+    }
+
+    fn reachable<I: Iterator<Item = NodeId>>(
+        start: NodeId,
+        next: impl Fn(NodeId) -> I,
+    ) -> HashSet<NodeId> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<_> = next(start).collect();
+
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(next(id));
+            }
+        }
+
+        seen
+    }
+
+    /// Computes a value for `start` from the values of its successors, e.g. the total weight below a node.
+    /// `f` receives a node and the weight and value of each of its outgoing edges, and is called once per
+    /// node reachable from `start`, however many paths lead to it.
+    ///
+    /// The traversal does not recurse, so long chains of nodes cannot overflow the stack.
+    /// Returns an error if a cycle is reachable from `start`, as its values would depend on themselves.
+    pub fn evaluate<T: Clone>(
+        &self,
+        start: NodeId,
+        mut f: impl FnMut(NodeId, &[(&W, T)]) -> T,
+    ) -> Result<T, CycleError> {
+        let mut values: Vec<Option<T>> = vec![None; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        // every entry is a node and the index of its next edge to visit.
+        let mut stack = vec![(start, 0)];
+        on_stack[start] = true;
+
+        while let Some((id, next)) = stack.last_mut() {
+            let id = *id;
+
+            if let Some((to, _)) = self.edges[id].get(*next) {
+                *next += 1;
+                if on_stack[*to] {
+                    return Err(CycleError {
+                        node: self.names[*to].clone(),
+                    });
+                }
+                if values[*to].is_none() {
+                    on_stack[*to] = true;
+                    stack.push((*to, 0));
+                }
+            } else {
+                let inputs: Vec<_> = self.edges[id]
+                    .iter()
+                    .map(|(to, weight)| (weight, values[*to].clone().unwrap()))
+                    .collect();
+                values[id] = Some(f(id, &inputs));
+                on_stack[id] = false;
+                stack.pop();
+            }
+        }
+
+        Ok(values[start].take().unwrap())
+    }
+
+    /// Orders the nodes so that every edge points from an earlier to a later node.
+    /// Returns an error if the graph contains a cycle, as no such order exists.
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut in_degree: Vec<_> = self.reverse.iter().map(Vec::len).collect();
+        let mut ready: Vec<_> = self.nodes().filter(|&id| in_degree[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(id) = ready.pop() {
+            order.push(id);
+            for &(to, _) in &self.edges[id] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    ready.push(to);
+                }
+            }
+        }
+
+        if order.len() == self.len() {
+            return Ok(order);
+        }
+
+        // every remaining node has a remaining predecessor, so walking backwards must eventually repeat a node.
+        let mut seen = HashSet::new();
+        let mut id = self.nodes().find(|&id| in_degree[id] > 0).unwrap();
+        while seen.insert(id) {
+            id = *self.reverse[id]
+                .iter()
+                .find(|&&from| in_degree[from] > 0)
+                .unwrap();
+        }
+        Err(CycleError {
+            node: self.names[id].clone(),
+        })
+    }
+
+    /// Returns whether the graph contains a cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.topological_order().is_err()
+    }
+
+    /// Renders the graph in the [DOT language](https://graphviz.org/doc/info/lang.html) of Graphviz,
+    /// labelling every edge with the result of `label`, if any.
+    pub fn to_dot(&self, label: impl Fn(&W) -> Option<String>) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut dot = String::from("digraph {\n");
+
+        for name in &self.names {
+            writeln!(dot, "    {};", quote(name)).unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, weight) in edges {
+                write!(
+                    dot,
+                    "    {} -> {}",
+                    quote(&self.names[from]),
+                    quote(&self.names[*to])
+                )
+                .unwrap();
+                if let Some(label) = label(weight) {
+                    write!(dot, " [label={}]", quote(&label)).unwrap();
+                }
+                dot.push_str(";\n");
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which is returned when an operation on a [`Graph`] requires it to be acyclic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// The name of a node on the cycle.
+    pub node: String,
+}
+
+impl Error for CycleError {}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "graph contains a cycle through `{}`", self.node)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{CycleError, Graph};
+
+    /// a → b → d, a → c → d, d → e
+    fn diamond() -> Graph<u32> {
+        let mut graph = Graph::new();
+        let [a, b, c, d, e] = ["a", "b", "c", "d", "e"].map(|name| graph.node(name));
+        graph.add_edge(a, b, 1);
+        graph.add_edge(a, c, 2);
+        graph.add_edge(b, d, 3);
+        graph.add_edge(c, d, 4);
+        graph.add_edge(d, e, 5);
+        graph
+    }
+
+    #[test]
+    fn interns_names() {
+        let mut graph = diamond();
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.node("c"), 2);
+        assert_eq!(graph.id("e"), Some(4));
+        assert_eq!(graph.id("f"), None);
+        assert_eq!(graph.name(3), "d");
+        assert_eq!(graph.successors(0), [(1, 1), (2, 2)]);
+        assert_eq!(graph.predecessors(3), [1, 2]);
+    }
+
+    #[test]
+    fn finds_reachable_nodes() {
+        let graph = diamond();
+        let mut ancestors: Vec<_> = graph.ancestors(3).into_iter().collect();
+        ancestors.sort_unstable();
+        assert_eq!(ancestors, [0, 1, 2]);
+        assert_eq!(graph.descendants(1).len(), 2);
+        assert!(graph.descendants(4).is_empty());
+    }
+
+    #[test]
+    fn evaluates_each_node_once() {
+        let graph = diamond();
+        let mut calls = 0;
+        let paths = graph.evaluate(0, |_, successors| {
+            calls += 1;
+            successors
+                .iter()
+                .map(|(_, paths)| paths)
+                .sum::<u32>()
+                .max(1)
+        });
+        assert_eq!(paths, Ok(2));
+        assert_eq!(calls, 5);
+
+        let weight = graph.evaluate(0, |_, successors| {
+            successors.iter().map(|&(&w, below)| w + below).sum::<u32>()
+        });
+        assert_eq!(weight, Ok(1 + 3 + 5 + 2 + 4 + 5));
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let mut graph = diamond();
+        let order = graph.topological_order().unwrap();
+        for from in graph.nodes() {
+            for &(to, _) in graph.successors(from) {
+                let position = |id| order.iter().position(|&node| node == id);
+                assert!(position(from) < position(to));
+            }
+        }
+
+        graph.add_edge(4, 1, 0);
+        assert!(graph.has_cycle());
+        let error = graph.topological_order().unwrap_err();
+        assert!(["b", "d", "e"].contains(&error.node.as_str()));
+        assert!(graph.evaluate(0, |_, _| 0).is_err());
+        assert_eq!(
+            graph.evaluate(2, |_, _| 0),
+            Err(CycleError { node: "d".into() })
+        );
+    }
+
+    #[test]
+    fn handles_long_chains() {
+        let mut graph = Graph::new();
+        let mut previous = graph.node("0");
+        for i in 1..100_000 {
+            let next = graph.node(&i.to_string());
+            graph.add_edge(previous, next, ());
+            previous = next;
+        }
+        assert_eq!(
+            graph.evaluate(0, |_, below| below.len() + below.first().map_or(0, |b| b.1)),
+            Ok(99_999)
+        );
+        assert_eq!(graph.ancestors(previous).len(), 99_999);
+    }
+
+    #[test]
+    fn renders_dot() {
+        let mut graph = Graph::new();
+        let a = graph.node("a");
+        let b = graph.node("say \"b\"");
+        graph.node("c");
+        graph.add_edge(a, b, 2);
+        assert_eq!(
+            graph.to_dot(|weight| Some(weight.to_string())),
+            "digraph {\n    \"a\";\n    \"say \\\"b\\\"\";\n    \"c\";\n    \"a\" -> \"say \\\"b\\\"\" [label=\"2\"];\n}"
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A position in a [`Grid`] as `(x, y)`, where `(0, 0)` is the top left cell.
+pub type Position = (usize, usize);
+
+/// A step between two positions as `(dx, dy)`.
+pub type Offset = (isize, isize);
+
+/// The four orthogonal neighbour offsets, clockwise starting upwards.
+pub const NEIGHBOURS_4: [Offset; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The eight orthogonal and diagonal neighbour offsets, clockwise starting upwards.
+pub const NEIGHBOURS_8: [Offset; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular 2D grid, stored row by row.
+///
+/// # Display
+/// This value displays as one line per row.
+///
+/// ```
+/// # use advent_of_code::Grid;
+/// let grid: Grid<char> = "#.\n.#\n".parse().unwrap();
+/// assert_eq!(grid[(1, 1)], '#');
+/// assert_eq!(grid.neighbours_8((0, 0)).filter(|&p| grid[p] == '#').count(), 1);
+/// assert_eq!(grid.to_string(), "#.\n.#");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a [`Grid`] from cells given row by row,
+    /// returns [`None`] if the number of cells does not match the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == width * height).then_some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a [`Grid`] with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses a character map with one row per line, converting each character with `cell`.
+    /// Fails if rows differ in length or `cell` returns [`None`].
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(GridError::Cell(c, (x, y)))?);
+                row_width += 1;
+            }
+
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(GridError::Ragged(y));
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, (x, y): Position) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// Returns the cell at `position`, or [`None`] if it is out of bounds.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index(position).map(|i| &self.cells[i])
+    }
+
+    /// Returns the cell at `position` mutably, or [`None`] if it is out of bounds.
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Returns the cell at `(x, y)` as if the grid was repeated infinitely in every direction.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn get_wrapping(&self, (x, y): Offset) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// Returns the position one `offset` away from `position`, or [`None`] if it is out of bounds.
+    pub fn offset(&self, (x, y): Position, (dx, dy): Offset) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.index(position).map(|_| position)
+    }
+
+    /// Returns the in-bounds neighbours of `position` at the given offsets.
+    pub fn neighbours<'a>(
+        &'a self,
+        position: Position,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Returns the in-bounds orthogonal neighbours of `position`.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    /// Returns the in-bounds orthogonal and diagonal neighbours of `position`.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    /// Returns the positions reached by repeatedly stepping `direction` from `position` until leaving the grid.
+    /// The starting position is not included.
+    pub fn ray(
+        &self,
+        position: Position,
+        direction: Offset,
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.offset(position, direction), move |&position| {
+            self.offset(position, direction)
+        })
+    }
+
+    /// Returns the positions of all cells, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns all cells, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Returns row `y`.
+    ///
+    /// # Panics
+    /// Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is out of bounds");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns column `x`, top to bottom.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns the rows, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Returns the columns, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Creates a grid of the same dimensions by applying `f` to every cell.
+    pub fn map<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self
+                .iter()
+                .map(|(position, cell)| f(position, cell))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {position:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {position:?} is out of bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses a character map, converting each character with [`TryFrom<char>`].
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |c| T::try_from(c).ok())
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A character could not be converted to a cell.
+    Cell(char, Position),
+    /// A row has a different length than the first row.
+    Ragged(usize),
+}
+
+impl Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Cell(c, (x, y)) => write!(f, "unexpected character `{c}` at {x},{y}"),
+            GridError::Ragged(y) => write!(f, "row {y} differs in length from the first row"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridError, NEIGHBOURS_8};
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\nghi\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\nghi");
+
+        let bools = Grid::parse("#.\n.#", |c| Some(c == '#')).unwrap();
+        assert_eq!(bools.values().filter(|&&b| b).count(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_maps() {
+        assert_eq!("ab\nc".parse::<Grid<char>>(), Err(GridError::Ragged(1)));
+        assert_eq!(
+            Grid::parse("..\n.x", |c| (c == '.').then_some(())),
+            Err(GridError::Cell('x', (1, 1)))
+        );
+        assert_eq!(Grid::new(2, 2, vec![1, 2, 3]), None);
+    }
+
+    #[test]
+    fn wraps_access() {
+        let grid = grid();
+        assert_eq!(*grid.get_wrapping((3, 0)), 'a');
+        assert_eq!(*grid.get_wrapping((-1, -1)), 'i');
+        assert_eq!(*grid.get_wrapping((7, 5)), 'h');
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        let cells = |positions: Vec<_>| positions.into_iter().map(|p| grid[p]).collect::<String>();
+
+        assert_eq!(cells(grid.neighbours_4((0, 0)).collect()), "bd");
+        assert_eq!(cells(grid.neighbours_4((1, 1)).collect()), "bfhd");
+        assert_eq!(cells(grid.neighbours_8((1, 1)).collect()), "bcfihgda");
+        assert_eq!(
+            cells(grid.neighbours((2, 2), &NEIGHBOURS_8).collect()),
+            "fhe"
+        );
+    }
+
+    #[test]
+    fn casts_rays() {
+        let grid = grid();
+        let cells = |positions: Vec<_>| positions.into_iter().map(|p| grid[p]).collect::<String>();
+
+        assert_eq!(cells(grid.ray((0, 0), (1, 1)).collect()), "ei");
+        assert_eq!(cells(grid.ray((2, 1), (-1, 0)).collect()), "ed");
+        assert_eq!(grid.ray((0, 0), (0, -1)).count(), 0);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let mut grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "beh");
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["adg", "beh", "cfi"]
+        );
+
+        grid[(1, 1)] = '#';
+        let mapped = grid.map(|(x, y), &c| if c == '#' { x + y } else { 0 });
+        assert_eq!(mapped.values().sum::<usize>(), 2);
+    }
+}
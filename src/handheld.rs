@@ -0,0 +1,416 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::space1, combinator::value,
+    sequence::separated_pair, IResult,
+};
+
+use crate::parse::{lines, parse_all, signed, ParseError};
+
+/// An instruction of the handheld game console's boot code.
+///
+/// # Display
+/// This value displays as in the source, e.g. `jmp -3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// Adds the operand to the accumulator.
+    Acc(i64),
+    /// Jumps relative to the current instruction.
+    Jmp(i64),
+    /// Does nothing.
+    Nop(i64),
+}
+
+impl Instruction {
+    pub fn operand(self) -> i64 {
+        match self {
+            Instruction::Acc(operand) | Instruction::Jmp(operand) | Instruction::Nop(operand) => {
+                operand
+            }
+        }
+    }
+
+    fn mnemonic(self) -> &'static str {
+        match self {
+            Instruction::Acc(_) => "acc",
+            Instruction::Jmp(_) => "jmp",
+            Instruction::Nop(_) => "nop",
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:+}", self.mnemonic(), self.operand())
+    }
+}
+
+fn instruction(input: &str) -> IResult<&str, Instruction> {
+    let (rest, (opcode, operand)) = separated_pair(
+        alt((
+            value(Instruction::Acc as fn(i64) -> Instruction, tag("acc")),
+            value(Instruction::Jmp as fn(i64) -> Instruction, tag("jmp")),
+            value(Instruction::Nop as fn(i64) -> Instruction, tag("nop")),
+        )),
+        space1,
+        signed,
+    )(input)?;
+    Ok((rest, opcode(operand)))
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(instruction, s)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A program of boot code with one [`Instruction`] per line.
+///
+/// # Display
+/// This value displays as its source, see [`Program::disassemble`] for a listing with addresses.
+///
+/// ```
+/// # use advent_of_code::{Halt, Program, Vm};
+/// let program: Program = "nop +0\nacc +1\njmp -2".parse().unwrap();
+/// let mut vm = Vm::new(&program);
+/// assert_eq!(vm.run(), Halt::Loop { pc: 0 });
+/// assert_eq!(vm.acc(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self { instructions }
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// The number of instructions.
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// Lists the instructions with their addresses, annotating every jump with its target.
+    pub fn disassemble(&self) -> String {
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(pc, instruction)| {
+                let line = format!("{pc:04}  {instruction}");
+                match instruction {
+                    Instruction::Jmp(offset) => match jump_target(pc, *offset, self.len()) {
+                        Ok(target) => format!("{line:<16}; -> {target:04}"),
+                        Err(_) => format!("{line:<16}; -> out of bounds"),
+                    },
+                    _ => line,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Index<usize> for Program {
+    type Output = Instruction;
+
+    fn index(&self, pc: usize) -> &Self::Output {
+        &self.instructions[pc]
+    }
+}
+
+impl IndexMut<usize> for Program {
+    fn index_mut(&mut self, pc: usize) -> &mut Self::Output {
+        &mut self.instructions[pc]
+    }
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (pc, instruction) in self.instructions.iter().enumerate() {
+            if pc > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{instruction}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(lines(instruction), s).map(Program::new)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The reason a [`Vm`] stopped executing.
+///
+/// # Display
+/// This value displays as a short description, e.g. `loop detected at pc 7`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Halt {
+    /// The program counter reached the instruction right after the last one.
+    Terminated,
+    /// The instruction at `pc` was about to be executed a second time, which would repeat forever.
+    Loop { pc: usize },
+    /// The jump at `pc` leads to `target`, which is neither an instruction nor the end of the program.
+    OutOfBounds { pc: usize, target: i64 },
+    /// The instruction at `pc` overflowed the accumulator or the jump target.
+    Overflow { pc: usize },
+}
+
+impl Display for Halt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Halt::Terminated => write!(f, "terminated"),
+            Halt::Loop { pc } => write!(f, "loop detected at pc {pc}"),
+            Halt::OutOfBounds { pc, target } => {
+                write!(f, "jump out of bounds at pc {pc} (to {target})")
+            }
+            Halt::Overflow { pc } => write!(f, "overflow at pc {pc}"),
+        }
+    }
+}
+
+/// An executed instruction, recorded by a [`Vm`] with tracing enabled.
+///
+/// # Display
+/// This value displays as the address, the instruction and the accumulator after executing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TraceStep {
+    pub pc: usize,
+    pub instruction: Instruction,
+    pub acc: i64,
+}
+
+impl Display for TraceStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}  {:<8}  acc = {}",
+            self.pc,
+            self.instruction.to_string(),
+            self.acc
+        )
+    }
+}
+
+/// Resolves a jump, returning the target as an error if it leaves the program (or cannot be represented).
+fn jump_target(pc: usize, offset: i64, len: usize) -> Result<usize, Option<i64>> {
+    let target = i64::try_from(pc)
+        .ok()
+        .and_then(|pc| pc.checked_add(offset))
+        .ok_or(None)?;
+
+    match usize::try_from(target) {
+        Ok(target) if target <= len => Ok(target),
+        _ => Err(Some(target)),
+    }
+}
+
+/// Executes a [`Program`], detecting infinite loops on the way.
+///
+/// As no instruction depends on the accumulator, executing any instruction a second time means that
+/// the program loops forever, so the virtual machine halts with [`Halt::Loop`] instead.
+/// Halting is sticky: stepping a halted virtual machine returns the same reason again.
+#[derive(Debug, Clone)]
+pub struct Vm<'a> {
+    program: &'a Program,
+    pc: usize,
+    acc: i64,
+    visited: Vec<bool>,
+    trace: Option<Vec<TraceStep>>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            pc: 0,
+            acc: 0,
+            visited: vec![false; program.len()],
+            trace: None,
+        }
+    }
+
+    /// Enables recording every executed instruction, see [`Vm::trace`].
+    #[must_use]
+    pub fn traced(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    /// The program counter, i.e. the address of the next instruction.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// The accumulator.
+    pub fn acc(&self) -> i64 {
+        self.acc
+    }
+
+    /// Returns the executed instructions in order, or nothing if tracing is not enabled.
+    pub fn trace(&self) -> &[TraceStep] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Executes the next instruction, returns the reason if the virtual machine halts instead.
+    pub fn step(&mut self) -> Option<Halt> {
+        let pc = self.pc;
+        if pc == self.program.len() {
+            return Some(Halt::Terminated);
+        }
+        if self.visited[pc] {
+            return Some(Halt::Loop { pc });
+        }
+
+        let instruction = self.program[pc];
+        match instruction {
+            Instruction::Acc(operand) => {
+                let Some(acc) = self.acc.checked_add(operand) else {
+                    return Some(Halt::Overflow { pc });
+                };
+                self.acc = acc;
+                self.pc += 1;
+            }
+            Instruction::Jmp(offset) => match jump_target(pc, offset, self.program.len()) {
+                Ok(target) => self.pc = target,
+                Err(Some(target)) => return Some(Halt::OutOfBounds { pc, target }),
+                Err(None) => return Some(Halt::Overflow { pc }),
+            },
+            Instruction::Nop(_) => self.pc += 1,
+        }
+
+        self.visited[pc] = true;
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceStep {
+                pc,
+                instruction,
+                acc: self.acc,
+            });
+        }
+        None
+    }
+
+    /// Executes instructions until the virtual machine halts.
+    pub fn run(&mut self) -> Halt {
+        loop {
+            if let Some(halt) = self.step() {
+                return halt;
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Halt, Instruction, Program, TraceStep, Vm};
+
+    const EXAMPLE: &str =
+        "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6\n";
+
+    #[test]
+    fn parses_programs() {
+        let program: Program = EXAMPLE.parse().unwrap();
+        assert_eq!(program.len(), 9);
+        assert_eq!(program[2], Instruction::Jmp(4));
+        assert_eq!(program.to_string(), EXAMPLE.trim_end());
+        assert_eq!("acc -7".parse(), Ok(Instruction::Acc(-7)));
+
+        let error = "nop +0\nmul +2\n".parse::<Program>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn detects_loops() {
+        let program: Program = EXAMPLE.parse().unwrap();
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Halt::Loop { pc: 1 });
+        assert_eq!(vm.acc(), 5);
+        assert_eq!(vm.step(), Some(Halt::Loop { pc: 1 }));
+    }
+
+    #[test]
+    fn terminates() {
+        let mut program: Program = EXAMPLE.parse().unwrap();
+        program[7] = Instruction::Nop(-4);
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Halt::Terminated);
+        assert_eq!((vm.pc(), vm.acc()), (9, 8));
+    }
+
+    #[test]
+    fn halts_on_invalid_jumps_and_overflows() {
+        let program: Program = "nop +0\njmp -2".parse().unwrap();
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Halt::OutOfBounds { pc: 1, target: -1 });
+        assert_eq!(vm.step(), Some(Halt::OutOfBounds { pc: 1, target: -1 }));
+
+        let program: Program = "jmp +3\nnop +0".parse().unwrap();
+        assert_eq!(
+            Vm::new(&program).run(),
+            Halt::OutOfBounds { pc: 0, target: 3 }
+        );
+
+        let program = Program::new(vec![Instruction::Acc(i64::MAX), Instruction::Acc(1)]);
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Halt::Overflow { pc: 1 });
+        assert_eq!(vm.acc(), i64::MAX);
+
+        let program = Program::new(vec![Instruction::Nop(0), Instruction::Jmp(i64::MAX)]);
+        assert_eq!(Vm::new(&program).run(), Halt::Overflow { pc: 1 });
+    }
+
+    #[test]
+    fn traces_execution() {
+        let program: Program = EXAMPLE.parse().unwrap();
+        let mut vm = Vm::new(&program).traced();
+        vm.run();
+
+        let pcs: Vec<_> = vm.trace().iter().map(|step| step.pc).collect();
+        assert_eq!(pcs, [0, 1, 2, 6, 7, 3, 4]);
+        assert_eq!(
+            vm.trace()[3],
+            TraceStep {
+                pc: 6,
+                instruction: Instruction::Acc(1),
+                acc: 2
+            }
+        );
+        assert_eq!(vm.trace()[3].to_string(), "0006  acc +1    acc = 2");
+
+        let mut untraced = Vm::new(&program);
+        untraced.run();
+        assert!(untraced.trace().is_empty());
+    }
+
+    #[test]
+    fn disassembles() {
+        let program: Program = "nop +0\njmp -1\njmp -5\njmp +1".parse().unwrap();
+        assert_eq!(
+            program.disassemble(),
+            "0000  nop +0\n0001  jmp -1    ; -> 0000\n0002  jmp -5    ; -> out of bounds\n0003  jmp +1    ; -> 0004"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::{FromStr, Lines};
+
+use nom::{character::complete::space0, sequence::delimited, Parser};
+
+use crate::parse::{groups, lines, parse_all, record, signed, ParseError};
+use crate::Grid;
+
+/// A puzzle input with `\r\n` line endings normalized to `\n`.
+///
+/// The runner passes the input to solutions taking `&Input` as well as to solutions taking `&str`.
+/// `Input` dereferences to `str`, so all string methods remain available.
+///
+/// # Panics
+/// The accessors panic with the position of the offending line if the input does not have the requested shape,
+/// use [`Input::parse`] to handle errors instead.
+///
+/// ```
+/// # use advent_of_code::Input;
+/// let input = Input::from("1\r\n2\r\n\r\n3\r\n");
+/// assert_eq!(input.groups().collect::<Vec<_>>(), ["1\n2", "3"]);
+/// assert_eq!(Input::from("1721\n979\n").ints::<u32>(), [1721, 979]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+        if text.contains('\r') {
+            Self {
+                text: text.replace("\r\n", "\n"),
+            }
+        } else {
+            Self { text }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the lines of the input.
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Returns the groups of lines separated by blank lines, without surrounding line breaks.
+    pub fn groups(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split("\n\n")
+            .map(|group| group.trim_matches('\n'))
+            .filter(|group| !group.is_empty())
+    }
+
+    /// Parses every line as an integer with an optional sign, ignoring surrounding spaces.
+    pub fn ints<T: FromStr>(&self) -> Vec<T> {
+        self.expect(lines(delimited(space0, signed, space0)))
+    }
+
+    /// Parses the input as a [`Grid`] of cells converted from characters.
+    pub fn grid<T: TryFrom<char>>(&self) -> Grid<T> {
+        self.text.parse().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Parses every group of lines as a record of `key:value` pairs, see [`crate::parse::record`].
+    pub fn records(&self) -> Vec<Vec<(&str, &str)>> {
+        self.expect(groups(record))
+    }
+
+    /// Runs `parser` on the whole input, see [`parse_all`].
+    pub fn parse<'a, O, F>(&'a self, parser: F) -> Result<O, ParseError>
+    where
+        F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+    {
+        parse_all(parser, &self.text)
+    }
+
+    fn expect<'a, O, F>(&'a self, parser: F) -> O
+    where
+        F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+    {
+        self.parse(parser).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+/// Allows the runner to pass an [`Input`] to solutions taking `&str`.
+impl<'a> From<&'a Input> for &'a str {
+    fn from(input: &'a Input) -> Self {
+        &input.text
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Input;
+    use crate::Grid;
+
+    #[test]
+    fn normalizes_line_endings() {
+        let input = Input::from("a\r\nb\r\n");
+        assert_eq!(input.as_str(), "a\nb\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(<&str>::from(&input), "a\nb\n");
+        assert!(input.starts_with('a'));
+    }
+
+    #[test]
+    fn splits_groups() {
+        let input = Input::from("abc\n\na\nb\nc\n\n\n\nab\n");
+        assert_eq!(input.groups().collect::<Vec<_>>(), ["abc", "a\nb\nc", "ab"]);
+        assert_eq!(Input::from("").groups().count(), 0);
+    }
+
+    #[test]
+    fn parses_shapes() {
+        assert_eq!(Input::from("+1\n-2\n3\n").ints::<i64>(), [1, -2, 3]);
+        assert_eq!(Input::from(" 1721 \n979\t\n").ints::<u32>(), [1721, 979]);
+        assert_eq!(
+            Input::from("a:1 b:2\r\nc:3\r\n\r\nd:4\r\n").records(),
+            [vec![("a", "1"), ("b", "2"), ("c", "3")], vec![("d", "4")]]
+        );
+
+        let grid: Grid<char> = Input::from("ab\r\ncd\r\n").grid();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 1)], 'd');
+    }
+
+    #[test]
+    #[should_panic(expected = "parse error at line 2, column 1")]
+    fn reports_invalid_integers() {
+        Input::from("1\nx\n").ints::<u8>();
+    }
+}
@@ -1,4 +1,27 @@
+mod automaton;
+mod calendar;
+mod conway;
 mod day;
+mod geometry;
+mod graph;
+mod grid;
+mod handheld;
+mod input;
+pub mod parse;
+mod part;
+mod puzzle_id;
 pub mod template;
+mod year;
 
+pub use automaton::*;
+pub use calendar::*;
+pub use conway::*;
 pub use day::*;
+pub use geometry::*;
+pub use graph::*;
+pub use grid::*;
+pub use handheld::*;
+pub use input::*;
+pub use part::*;
+pub use puzzle_id::*;
+pub use year::*;
@@ -1,56 +1,193 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench, download, examples, leaderboard, progress, read, scaffold, solve,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::leaderboard;
+    use advent_of_code::{template::default_year, Calendar, Day, Part, Year};
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
+            wait: bool,
+            scaffold: bool,
+        },
+        Examples {
+            year: Year,
+            day: Day,
+        },
+        BenchHistory {
+            year: Year,
+            day: Day,
+        },
+        Leaderboard {
+            year: Year,
+            source: leaderboard::Source,
+            day: Option<Day>,
+        },
+        Progress {
+            year: Year,
         },
         Read {
+            year: Year,
             day: Day,
         },
         Scaffold {
+            year: Year,
             day: Day,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             time: bool,
-            submit: Option<u8>,
+            submit: Option<Part>,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
         },
     }
 
+    /// Parses the `--year` option, falling back to the year configured in `aoc.toml` or via `AOC_YEAR`.
+    fn year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => default_year().ok_or_else(|| {
+                "no year specified. Pass `--year <year>` or set `year` in `aoc.toml`.".into()
+            }),
+        }
+    }
+
+    /// Parses the day argument, validating it against the calendar of `year`.
+    /// Falls back to the current day while the event of `year` is running.
+    /// Since the day is optional, it has to be parsed after all flags and options.
+    fn day(args: &mut pico_args::Arguments, year: Year) -> Result<Day, Box<dyn std::error::Error>> {
+        match args.opt_free_from_str()? {
+            Some(day) => calendar_day(year, day),
+            None => Day::today_of(year).ok_or_else(|| {
+                format!("no day specified and the {year} event is not running.").into()
+            }),
+        }
+    }
+
+    /// Validates a day number against the calendar of `year`.
+    fn calendar_day(year: Year, day: u8) -> Result<Day, Box<dyn std::error::Error>> {
+        let calendar = Calendar::for_year(year);
+        calendar.day(day).ok_or_else(|| {
+            format!(
+                "expecting a day number between 1 and {} for {year}",
+                calendar.day_count()
+            )
+            .into()
+        })
+    }
+
+    /// Validates that the part passed to `--submit` exists on `day`.
+    fn check_submit(
+        part: Option<Part>,
+        year: Year,
+        day: Day,
+    ) -> Result<Option<Part>, Box<dyn std::error::Error>> {
+        match part {
+            Some(part) if !Calendar::for_year(year).has_part(day, part) => {
+                Err(format!("day {day} of {year} has no part {part}").into())
+            }
+            part => Ok(part),
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: year(&mut args)?,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
             },
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+            Some("download") => {
+                let year = year(&mut args)?;
+                AppArguments::Download {
+                    year,
+                    wait: args.contains("--wait"),
+                    scaffold: args.contains("--scaffold"),
+                    day: day(&mut args, year)?,
+                }
+            }
+            Some("examples") => {
+                let year = year(&mut args)?;
+                AppArguments::Examples {
+                    year,
+                    day: day(&mut args, year)?,
+                }
+            }
+            Some("bench") => match args.subcommand()?.as_deref() {
+                Some("history") => {
+                    let year = year(&mut args)?;
+                    AppArguments::BenchHistory {
+                        year,
+                        day: day(&mut args, year)?,
+                    }
+                }
+                _ => {
+                    eprintln!("Unknown bench command. Usage: bench history <day>");
+                    process::exit(1);
+                }
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+            Some("leaderboard") => {
+                let year = year(&mut args)?;
+                let file = args.opt_value_from_str("--file")?;
+                let day = args
+                    .opt_value_from_str("--day")?
+                    .map(|day| calendar_day(year, day))
+                    .transpose()?;
+                // the id is only needed to fetch the leaderboard, not to read it from a file.
+                let source = match (file, args.opt_free_from_str()?) {
+                    (Some(path), _) => leaderboard::Source::File(path),
+                    (None, Some(id)) => leaderboard::Source::Api(id),
+                    (None, None) => {
+                        return Err("expecting a leaderboard id or `--file <path>`".into())
+                    }
+                };
+                AppArguments::Leaderboard { year, source, day }
+            }
+            Some("progress") => AppArguments::Progress {
+                year: year(&mut args)?,
             },
+            Some("read") => {
+                let year = year(&mut args)?;
+                AppArguments::Read {
+                    year,
+                    day: day(&mut args, year)?,
+                }
+            }
+            Some("scaffold") => {
+                let year = year(&mut args)?;
+                AppArguments::Scaffold {
+                    year,
+                    day: day(&mut args, year)?,
+                }
+            }
+            Some("solve") => {
+                let year = year(&mut args)?;
+                let release = args.contains("--release");
+                let time = args.contains("--time");
+                let submit = args.opt_value_from_str("--submit")?;
+                let day = day(&mut args, year)?;
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release,
+                    time,
+                    submit: check_submit(submit, year, day)?,
+                }
+            }
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -77,16 +214,32 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+            } => all::handle(year, release, time),
+            AppArguments::Download {
+                year,
+                day,
+                wait,
+                scaffold,
+            } => download::handle(year, day, wait, scaffold),
+            AppArguments::BenchHistory { year, day } => bench::handle_history(year, day),
+            AppArguments::Examples { year, day } => examples::handle(year, day),
+            AppArguments::Leaderboard { year, source, day } => {
+                leaderboard::handle(year, source, day);
+            }
+            AppArguments::Progress { year } => progress::handle(year),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Scaffold { year, day } => scaffold::handle(year, day),
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 time,
                 submit,
-            } => solve::handle(day, release, time, submit),
+            } => solve::handle(year, day, release, time, submit),
         },
     };
 }
@@ -0,0 +1,243 @@
+//! Reusable [nom](https://docs.rs/nom) combinators for puzzle inputs.
+//!
+//! Parsers built from these combinators are run with [`parse_all`], which requires the whole input to be consumed
+//! and reports failures with their line and column:
+//!
+//! ```
+//! # use advent_of_code::parse::{lines, parse_all, signed};
+//! assert_eq!(parse_all(lines(signed::<i32>), "1\n-2\n+3\n").unwrap(), [1, -2, 3]);
+//!
+//! let error = parse_all(lines(signed::<i32>), "1\nx\n").unwrap_err();
+//! assert_eq!((error.line, error.column), (2, 1));
+//! ```
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use nom::{
+    branch::alt,
+    bytes::complete::take_till1,
+    character::complete::{char, digit1, line_ending, one_of, space1},
+    combinator::{all_consuming, eof, map_res, not, opt, recognize},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{pair, separated_pair, terminated},
+    IResult, Parser,
+};
+
+/// Parses an unsigned integer.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses an integer with an optional `+` or `-` sign.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+fn starts_with_line_ending(input: &str) -> bool {
+    input.starts_with('\n') || input.starts_with("\r\n")
+}
+
+/// Applies `line` to every line until the end of the input or a blank line.
+/// Unlike [`separated_list1`], a line that `line` does not fully consume is an error instead of the end of the list.
+pub fn lines<'a, O, F>(mut line: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    move |mut input: &'a str| {
+        let mut items = vec![];
+
+        while !input.is_empty() && !starts_with_line_ending(input) {
+            let (rest, item) = line.parse(input)?;
+            let (rest, _) = alt((line_ending, eof))(rest)?;
+            items.push(item);
+            input = rest;
+        }
+
+        Ok((input, items))
+    }
+}
+
+/// Applies `group` to every group of lines, where groups are separated by a blank line.
+/// `group` is expected to stop at a blank line, as [`lines`] and [`record`] do.
+pub fn groups<'a, O, F>(mut group: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    move |mut input: &'a str| {
+        let mut items = vec![];
+
+        loop {
+            let (rest, item) = group.parse(input)?;
+            items.push(item);
+
+            // the line ending of the last line of a group may or may not have been consumed.
+            let (rest, first) = opt(line_ending)(rest)?;
+            let (rest, _) = opt(line_ending)(rest)?;
+
+            if first.is_none() || rest.is_empty() {
+                return Ok((rest, items));
+            }
+            input = rest;
+        }
+    }
+}
+
+/// Parses a `key:value` pair (with `:` being `separator`), where neither key nor value contain whitespace.
+pub fn key_value<'a>(
+    separator: char,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, &'a str)> {
+    separated_pair(
+        take_till1(move |c: char| c == separator || c.is_whitespace()),
+        char(separator),
+        take_till1(char::is_whitespace),
+    )
+}
+
+/// Parses a record of `key:value` pairs, separated by spaces or single line breaks, until a blank line.
+pub fn record(input: &str) -> IResult<&str, Vec<(&str, &str)>> {
+    separated_list1(
+        alt((space1, terminated(line_ending, not(line_ending)))),
+        key_value(':'),
+    )(input)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Runs `parser` on `input`, requiring it to consume the whole input except for trailing line breaks.
+pub fn parse_all<'a, O, F>(parser: F, input: &'a str) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    let trimmed = input.trim_end_matches(['\r', '\n']);
+
+    match all_consuming(parser)(trimmed) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::new(
+            input,
+            trimmed.len() - e.input.len(),
+            e.code,
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(input, trimmed.len(), ErrorKind::Eof)),
+    }
+}
+
+/// An error which can be returned by [`parse_all`], pointing at the position where parsing failed.
+///
+/// # Display
+/// This value displays as a message followed by the affected line, with a caret below the position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the error, counting from 1.
+    pub line: usize,
+    /// The column of the error in characters, counting from 1.
+    pub column: usize,
+    /// The contents of the affected line.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(input: &str, offset: usize, kind: ErrorKind) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let snippet = input[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        let message = match kind {
+            ErrorKind::Eof => "unexpected input".into(),
+            ErrorKind::Digit => "expected a number".into(),
+            ErrorKind::MapRes => "invalid value".into(),
+            ErrorKind::Char | ErrorKind::Tag | ErrorKind::OneOf => "unexpected character".into(),
+            ErrorKind::CrLf => "expected a line break".into(),
+            kind => format!("expected {}", kind.description().to_lowercase()),
+        };
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet,
+            message,
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "parse error at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet.trim_end_matches('\r'))?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::character::complete::{alpha1, char};
+    use nom::sequence::separated_pair;
+
+    use super::{groups, lines, parse_all, record, signed, unsigned};
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(parse_all(signed::<i64>, "-42"), Ok(-42));
+        assert_eq!(parse_all(signed::<i8>, "+7"), Ok(7));
+        assert_eq!(parse_all(unsigned::<u32>, "42\n"), Ok(42));
+        assert!(parse_all(unsigned::<u32>, "-42").is_err());
+        assert!(parse_all(unsigned::<u8>, "256").is_err());
+    }
+
+    #[test]
+    fn parses_lines_and_groups() {
+        assert_eq!(
+            parse_all(lines(unsigned::<u8>), "1\r\n2\r\n3\r\n"),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(
+            parse_all(groups(lines(alpha1)), "ab\nc\n\nd\n\ne\nf\n"),
+            Ok(vec![vec!["ab", "c"], vec!["d"], vec!["e", "f"]])
+        );
+    }
+
+    #[test]
+    fn parses_records() {
+        let input = "ecl:gry pid:860033327\nbyr:1937\n\nhcl:#ae17e1 iyr:2013\n";
+        assert_eq!(
+            parse_all(groups(record), input),
+            Ok(vec![
+                vec![("ecl", "gry"), ("pid", "860033327"), ("byr", "1937")],
+                vec![("hcl", "#ae17e1"), ("iyr", "2013")]
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let range = || lines(separated_pair(unsigned::<u8>, char('-'), unsigned::<u8>));
+        assert!(parse_all(range(), "1-3\n4-5\n").is_ok());
+
+        let error = parse_all(range(), "1-3\n4-x\n6-7").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 3: expected a number\n  |\n2 | 4-x\n  |   ^"
+        );
+
+        // trailing input on a line is not silently ignored.
+        let error = parse_all(lines(unsigned::<u8>), "1\n2 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
@@ -0,0 +1,116 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Day, DayFromStrError, Part, PartFromStrError, Year, YearFromStrError};
+
+/// Identifies one part of a puzzle by its year, day and part.
+///
+/// # Display
+/// This value displays as `year/day/part`.
+///
+/// ```
+/// # use advent_of_code::{day, year, Part, PuzzleId};
+/// let puzzle = PuzzleId::new(year!(2020), day!(8), Part::Two);
+/// assert_eq!(puzzle.to_string(), "2020/08/2");
+/// assert_eq!("2020/08/2".parse::<PuzzleId>().unwrap(), puzzle);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+}
+
+impl PuzzleId {
+    pub fn new(year: Year, day: Day, part: Part) -> Self {
+        Self { year, day, part }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.year, self.day, self.part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.split('/');
+
+        let (Some(year), Some(day), Some(part), None) = (
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next(),
+        ) else {
+            return Err(PuzzleIdFromStrError::Format);
+        };
+
+        Ok(Self {
+            year: year.parse().map_err(PuzzleIdFromStrError::Year)?,
+            day: day.parse().map_err(PuzzleIdFromStrError::Day)?,
+            part: part.parse().map_err(PuzzleIdFromStrError::Part)?,
+        })
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub enum PuzzleIdFromStrError {
+    Format,
+    Year(YearFromStrError),
+    Day(DayFromStrError),
+    Part(PartFromStrError),
+}
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PuzzleIdFromStrError::Format => {
+                f.write_str("expecting a puzzle in the format `year/day/part`, e.g. `2020/08/2`")
+            }
+            PuzzleIdFromStrError::Year(e) => write!(f, "{e}"),
+            PuzzleIdFromStrError::Day(e) => write!(f, "{e}"),
+            PuzzleIdFromStrError::Part(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, year, Part};
+
+    #[test]
+    fn parses_puzzle_ids() {
+        assert_eq!(
+            "2020/8/1".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(year!(2020), day!(8), Part::One)
+        );
+        assert_eq!(
+            "2020/08/2".parse::<PuzzleId>().unwrap().to_string(),
+            "2020/08/2"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_puzzle_ids() {
+        assert!("2020/08".parse::<PuzzleId>().is_err());
+        assert!("2020/08/3".parse::<PuzzleId>().is_err());
+        assert!("2020/26/1".parse::<PuzzleId>().is_err());
+        assert!("2014/08/1".parse::<PuzzleId>().is_err());
+        assert!("2020/08/1/1".parse::<PuzzleId>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,108 @@
+/// Module that stores accepted answers to detect regressions in solutions.
+/// Answers are recorded from the "Your puzzle answer was …" lines of solved puzzle descriptions.
+use std::{fs, io};
+
+use crate::template::get_data_dir;
+use crate::{Day, Part, PuzzleId, Year};
+
+#[must_use]
+pub fn get_answers_path(year: Year, day: Day) -> String {
+    format!("{}/answers/{day}.txt", get_data_dir(year))
+}
+
+/// Extracts the accepted answers for both parts from a puzzle description.
+#[must_use]
+pub fn extract(markdown: &str) -> [Option<String>; 2] {
+    let mut answers = markdown.lines().filter_map(|line| {
+        let answer = line.trim().strip_prefix("Your puzzle answer was")?;
+        let answer = answer.trim().trim_end_matches('.').trim().trim_matches('`');
+        Some(answer.trim().to_string())
+    });
+
+    [answers.next(), answers.next()]
+}
+
+fn parse(content: &str) -> [Option<String>; 2] {
+    let answer = |prefix: &str| {
+        content
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(|answer| answer.trim().to_string())
+            .filter(|answer| !answer.is_empty())
+    };
+
+    [answer("Part 1:"), answer("Part 2:")]
+}
+
+fn format(answers: &[Option<String>; 2]) -> String {
+    answers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, answer)| Some(format!("Part {}: {}\n", i + 1, answer.as_ref()?)))
+        .collect()
+}
+
+/// Reads the accepted answers for `day`.
+#[must_use]
+pub fn read(year: Year, day: Day) -> [Option<String>; 2] {
+    fs::read_to_string(get_answers_path(year, day))
+        .map(|content| parse(&content))
+        .unwrap_or_default()
+}
+
+/// Reads the accepted answer for one part of a puzzle.
+#[must_use]
+pub fn read_part(puzzle: PuzzleId) -> Option<String> {
+    let [part_1, part_2] = read(puzzle.year, puzzle.day);
+    match puzzle.part {
+        Part::One => part_1,
+        Part::Two => part_2,
+    }
+}
+
+/// Records the accepted answers found in a puzzle description for `day`.
+/// Previously recorded answers are kept if the description does not contain them.
+/// Returns the answers that were newly recorded.
+pub fn record(year: Year, day: Day, markdown: &str) -> Result<Vec<(Part, String)>, io::Error> {
+    let mut answers = read(year, day);
+    let mut recorded = vec![];
+    let parts = [Part::One, Part::Two].into_iter().zip(&mut answers);
+
+    for ((part, answer), found) in parts.zip(extract(markdown)) {
+        if let Some(found) = found {
+            if answer.as_ref() != Some(&found) {
+                recorded.push((part, found.clone()));
+                *answer = Some(found);
+            }
+        }
+    }
+
+    if !recorded.is_empty() {
+        fs::create_dir_all(format!("{}/answers", get_data_dir(year)))?;
+        fs::write(get_answers_path(year, day), format(&answers))?;
+    }
+
+    Ok(recorded)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, format, parse};
+
+    #[test]
+    fn extracts_answers() {
+        let md = "## --- Day 1 ---\n\nYour puzzle answer was `1234`.\n\n## --- Part Two ---\n\nYour puzzle answer was `abc def`.\n";
+        assert_eq!(
+            extract(md),
+            [Some("1234".to_string()), Some("abc def".to_string())]
+        );
+        assert_eq!(extract("## --- Day 1 ---\n"), [None, None]);
+    }
+
+    #[test]
+    fn roundtrips_answers() {
+        let answers = [None, Some("42".to_string())];
+        assert_eq!(format(&answers), "Part 2: 42\n");
+        assert_eq!(parse(&format(&answers)), answers);
+    }
+}
@@ -0,0 +1,112 @@
+/// Wrapper module around requests to the adventofcode.com website.
+/// Requests are made with "curl", authenticated with the same session cookie that aoc-cli uses.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+use crate::{Day, Year};
+
+const BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum AocWebError {
+    CommandNotFound,
+    MissingSession,
+    BadExitStatus(String),
+    IoError,
+}
+
+impl Display for AocWebError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocWebError::CommandNotFound => write!(f, "curl is not present in environment."),
+            AocWebError::MissingSession => write!(
+                f,
+                "no session cookie found. Set ADVENT_OF_CODE_SESSION or create ~/.adventofcode.session."
+            ),
+            AocWebError::BadExitStatus(stderr) => write!(f, "request failed: {}", stderr.trim()),
+            AocWebError::IoError => write!(f, "could not communicate with curl."),
+        }
+    }
+}
+
+/// Returns the absolute URL for a path on adventofcode.com.
+#[must_use]
+pub fn url(path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string()
+    } else {
+        format!("{BASE_URL}{path}")
+    }
+}
+
+/// Returns the URL of the puzzle page for a given day.
+#[must_use]
+pub fn puzzle_url(year: Year, day: Day) -> String {
+    url(&format!("/{year}/day/{}", day.into_inner()))
+}
+
+/// Reads the session cookie, looking up the same locations as aoc-cli.
+fn get_session() -> Result<String, AocWebError> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+
+    let candidates = [
+        home.as_ref().map(|h| h.join(".adventofcode.session")),
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".config")))
+            .map(|c| c.join("adventofcode.session")),
+    ];
+
+    candidates
+        .into_iter()
+        .flatten()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocWebError::MissingSession)
+}
+
+/// Fetches a page from adventofcode.com and returns the response body.
+pub fn fetch(path: &str) -> Result<String, AocWebError> {
+    let session = get_session()?;
+
+    // the cookie header is passed via stdin so that the session does not show up in the process list.
+    let mut cmd = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--header", "@-"])
+        .args(["--user-agent", "github.com/fspoettel/advent-of-code-rust"])
+        .arg(url(path))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| AocWebError::CommandNotFound)?;
+
+    cmd.stdin
+        .take()
+        .ok_or(AocWebError::IoError)?
+        .write_all(format!("Cookie: session={session}\n").as_bytes())
+        .map_err(|_| AocWebError::IoError)?;
+
+    let output = cmd.wait_with_output().map_err(|_| AocWebError::IoError)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(AocWebError::BadExitStatus(
+            String::from_utf8_lossy(&output.stderr).to_string(),
+        ))
+    }
+}
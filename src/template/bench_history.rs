@@ -0,0 +1,342 @@
+/// Module that keeps a history of benchmark runs, so that the performance of a day can be followed across commits.
+/// Every timed release run appends one JSON line per day to `data/<year>/benchmarks.jsonl`.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::template::datetime::{self, DateTime};
+use crate::template::get_data_dir;
+use crate::template::readme_benchmarks::Timings;
+use crate::template::runner::Measurement;
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Json(serde_json::Error),
+    Format(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Json(e) => write!(f, "invalid history entry: {e}"),
+            Error::Format(field) => write!(f, "unexpected history format: {field}"),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_history_path(year: Year) -> String {
+    format!("{}/benchmarks.jsonl", get_data_dir(year))
+}
+
+/// One timed run of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Unix timestamp of the run.
+    pub timestamp: i64,
+    /// The git commit the run was made at, if available.
+    pub commit: Option<String>,
+    /// The output of `rustc --version`, if available.
+    pub rustc: Option<String>,
+    pub timings: Timings,
+}
+
+/// Runs a command and returns its trimmed output if it succeeded.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!output.is_empty()).then_some(output)
+}
+
+/// Returns the current git commit, suffixed with `-dirty` if the working tree has changes.
+fn git_commit() -> Option<String> {
+    let commit = command_output("git", &["rev-parse", "--short", "HEAD"])?;
+    let dirty = command_output("git", &["status", "--porcelain"]).is_some();
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+// The history is written by every timed run, while the serde derives of `Timings` are only available
+// with the optional `serde` feature. Entries are therefore converted by hand using `serde_json`, which is
+// always available. Durations are stored as integer nanoseconds, so the lines stay compact and the format
+// does not change with the serialized representation of `Duration` or `Timings`.
+#[allow(clippy::cast_possible_truncation)]
+fn measurement_to_json(measurement: Option<Measurement>) -> Value {
+    measurement.map_or(Value::Null, |m| {
+        json!({
+            "mean_ns": m.mean.as_nanos() as u64,
+            "stddev_ns": m.stddev.as_nanos() as u64,
+            "samples": m.samples as u64,
+        })
+    })
+}
+
+fn measurement_from_json(value: Option<&Value>) -> Result<Option<Measurement>, Error> {
+    let value = match value {
+        None | Some(Value::Null) => return Ok(None),
+        Some(value) => value,
+    };
+
+    let number = |key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_u64)
+            .ok_or_else(|| Error::Format(key.into()))
+    };
+
+    Ok(Some(Measurement {
+        mean: Duration::from_nanos(number("mean_ns")?),
+        stddev: Duration::from_nanos(number("stddev_ns")?),
+        samples: u128::from(number("samples")?),
+    }))
+}
+
+impl Entry {
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        json!({
+            "timestamp": self.timestamp,
+            "commit": self.commit,
+            "rustc": self.rustc,
+            "day": self.timings.day.into_inner(),
+            "parse": measurement_to_json(self.timings.parse),
+            "part_1": measurement_to_json(self.timings.part_1),
+            "part_2": measurement_to_json(self.timings.part_2),
+            "peak_memory_kib": self.timings.peak_memory,
+        })
+        .to_string()
+    }
+
+    pub fn from_json(line: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(line)?;
+        let string = |key: &str| value.get(key).and_then(Value::as_str).map(String::from);
+
+        let day = value
+            .get("day")
+            .and_then(Value::as_u64)
+            .and_then(|day| Day::new(u8::try_from(day).ok()?))
+            .ok_or_else(|| Error::Format("day".into()))?;
+
+        let parse = measurement_from_json(value.get("parse"))?;
+        let part_1 = measurement_from_json(value.get("part_1"))?;
+        let part_2 = measurement_from_json(value.get("part_2"))?;
+        let total = [parse, part_1, part_2]
+            .iter()
+            .flatten()
+            .map(|m| m.mean)
+            .sum();
+
+        Ok(Self {
+            timestamp: value
+                .get("timestamp")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| Error::Format("timestamp".into()))?,
+            commit: string("commit"),
+            rustc: string("rustc"),
+            timings: Timings {
+                day,
+                parse,
+                part_1,
+                part_2,
+                peak_memory: value.get("peak_memory_kib").and_then(Value::as_u64),
+                total,
+            },
+        })
+    }
+}
+
+/// Appends the timings of a run to the history of `year`.
+pub fn record(year: Year, timings: &[Timings]) -> Result<(), Error> {
+    let timestamp = datetime::now_timestamp();
+    let commit = git_commit();
+    let rustc = command_output("rustc", &["--version"]);
+
+    let lines: String = timings
+        .iter()
+        .map(|timings| {
+            let entry = Entry {
+                timestamp,
+                commit: commit.clone(),
+                rustc: rustc.clone(),
+                timings: timings.clone(),
+            };
+            format!("{}\n", entry.to_json())
+        })
+        .collect();
+
+    fs::create_dir_all(get_data_dir(year))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path(year))?;
+    file.write_all(lines.as_bytes())?;
+    Ok(())
+}
+
+/// Reads the history entries of `day`, oldest first.
+pub fn read(year: Year, day: Day) -> Result<Vec<Entry>, Error> {
+    let content = match fs::read_to_string(get_history_path(year)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let entries = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Entry::from_json)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.timings.day == day)
+        .collect())
+}
+
+fn format_duration(measurement: Option<Measurement>) -> String {
+    measurement.map_or_else(|| "-".into(), |m| format!("{:.1?}", m.mean))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_change(current: Duration, previous: Option<Duration>) -> String {
+    match previous {
+        Some(previous) if !previous.is_zero() => {
+            let change = (current.as_nanos() as f64 / previous.as_nanos() as f64 - 1_f64) * 100_f64;
+            format!("{change:+.1}%")
+        }
+        _ => "-".into(),
+    }
+}
+
+/// Renders the history of a day as a table, with the change of the total time compared to the previous run.
+/// Dates are shown in the timezone `utc_offset` seconds from UTC.
+#[must_use]
+pub fn render(entries: &[Entry], utc_offset: i64) -> String {
+    let rows: Vec<[String; 7]> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let previous = i.checked_sub(1).map(|i| entries[i].timings.total);
+            let rustc = entry.rustc.as_deref().map_or("-", |rustc| {
+                rustc.split_whitespace().nth(1).unwrap_or(rustc)
+            });
+
+            [
+                DateTime::from_timestamp(entry.timestamp, utc_offset).to_string(),
+                entry.commit.clone().unwrap_or_else(|| "-".into()),
+                rustc.to_string(),
+                format_duration(entry.timings.parse),
+                format_duration(entry.timings.part_1),
+                format_duration(entry.timings.part_2),
+                format_change(entry.timings.total, previous),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Date", "Commit", "Rustc", "Parse", "Part 1", "Part 2", "Change",
+    ]
+    .map(String::from);
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{render, Entry};
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::runner::Measurement;
+    use crate::{day, Calendar};
+
+    fn entry(timestamp: i64, part_1_millis: u64) -> Entry {
+        let part_1 = Measurement {
+            mean: Duration::from_millis(part_1_millis),
+            stddev: Duration::from_micros(5),
+            samples: 100,
+        };
+
+        Entry {
+            timestamp,
+            commit: Some("abc1234".into()),
+            rustc: Some("rustc 1.75.0 (82e1608df 2023-12-21)".into()),
+            timings: Timings {
+                day: day!(1),
+                parse: None,
+                part_1: Some(part_1),
+                part_2: None,
+                peak_memory: Some(2048),
+                total: part_1.mean,
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = entry(1_606_798_800, 10);
+        assert_eq!(Entry::from_json(&entry.to_json()).unwrap(), entry);
+    }
+
+    #[test]
+    fn renders_history() {
+        let entries = [entry(1_606_798_800, 10), entry(1_606_885_200, 5)];
+        let expected = [
+            "Date                 Commit   Rustc   Parse  Part 1  Part 2  Change",
+            "2020-12-01 00:00:00  abc1234  1.75.0  -      10.0ms  -       -",
+            "2020-12-02 00:00:00  abc1234  1.75.0  -      5.0ms   -       -50.0%",
+        ]
+        .join("\n");
+        assert_eq!(
+            render(&entries, Calendar::ADVENT_OF_CODE.unlock().utc_offset),
+            expected
+        );
+    }
+}
@@ -1,15 +1,17 @@
 use std::io;
+use std::time::Duration;
 
 use crate::template::{
+    bench_history,
     readme_benchmarks::{self, Timings},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Calendar, Year};
 
-pub fn handle(is_release: bool, is_timed: bool) {
+pub fn handle(year: Year, is_release: bool, is_timed: bool) {
     let mut timings: Vec<Timings> = vec![];
 
-    all_days().for_each(|day| {
+    Calendar::for_year(year).days().for_each(|day| {
         if day > 1 {
             println!();
         }
@@ -17,7 +19,7 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        let output = child_commands::run_solution(year, day, is_timed, is_release).unwrap();
 
         if output.is_empty() {
             println!("Not solved.");
@@ -28,15 +30,31 @@ pub fn handle(is_release: bool, is_timed: bool) {
     });
 
     if is_timed {
-        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+        let total_millis = timings
+            .iter()
+            .map(|x| x.total)
+            .sum::<Duration>()
+            .as_secs_f64()
+            * 1000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+            if let Err(e) = bench_history::record(year, &timings) {
+                eprintln!("Failed to record benchmark history: {e}");
+            }
+
+            if !readme_benchmarks::tracks_year(year) {
+                println!(
+                    "Skipped updating the README, its benchmark table holds the timings of another year."
+                );
+                return;
+            }
+
+            match readme_benchmarks::update(year, timings, total_millis) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
-                Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+                Err(e) => {
+                    eprintln!("Failed to update readme with benchmarks: {e}");
                 }
             }
         }
@@ -56,32 +74,36 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
+pub(crate) mod child_commands {
+    use super::Error;
+    use crate::template::readme_benchmarks::parse_duration;
+    use crate::template::runner::Measurement;
+    use crate::template::{get_bin_name, get_path_for_bin};
+    use crate::{Day, Year};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
         process::{Command, Stdio},
         thread,
+        time::Duration,
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = get_bin_name(year, day);
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -93,14 +115,13 @@ mod child_commands {
             args.push("--time");
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        capture_output(Command::new("cargo").args(&args))
+    }
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    /// Runs a command, forwarding its output to stdout/stderr while grabbing stdout lines.
+    pub fn capture_output(cmd: &mut Command) -> Result<Vec<String>, Error> {
+        // spawn child command with piped stdout/stderr.
+        let mut cmd = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
 
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
@@ -128,80 +149,73 @@ mod child_commands {
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
         let mut timings = super::Timings {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
-            total_nanos: 0_f64,
+            peak_memory: None,
+            total: Duration::ZERO,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for line in output {
+            if let Some(kib) = line.strip_prefix("Peak memory:") {
+                timings.peak_memory = kib.trim().trim_end_matches("KiB").trim().parse().ok();
+                continue;
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if !line.contains(" samples)") {
+                continue;
+            }
 
-                timings.total_nanos += nanos;
-            });
+            let Some(measurement) = parse_measurement(line) else {
+                eprintln!("Could not parse timings from line: {line}");
+                continue;
+            };
+
+            let Some(part) = line.split(':').next() else {
+                continue;
+            };
+
+            if part.contains("Parse") {
+                timings.parse = Some(measurement);
+            } else if part.contains("Part 1") {
+                timings.part_1 = Some(measurement);
+            } else if part.contains("Part 2") {
+                timings.part_2 = Some(measurement);
+            } else {
+                continue;
+            }
 
-        timings
-    }
+            timings.total += measurement.mean;
+        }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        timings
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
+    /// Parses the measurement printed by the runner, e.g. `(74.1ns ± 1.0ns @ 100 samples)`.
+    fn parse_measurement(line: &str) -> Option<Measurement> {
+        let (time, samples) = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
+            .next_back()?
+            .split_once('@')?;
 
-        Some((str_timing, parsed_timing))
-    }
+        let (mean, stddev) = match time.split_once('±') {
+            Some((mean, stddev)) => (mean, parse_duration(stddev)?),
+            None => (time, Duration::ZERO),
+        };
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+        Some(Measurement {
+            mean: parse_duration(mean)?,
+            stddev,
+            samples: samples.trim().parse().ok()?,
+        })
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
+        use std::time::Duration;
+
         use super::parse_exec_time;
 
         use crate::day;
@@ -216,9 +230,10 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.total, Duration::from_nanos(74130074));
+            assert_eq!(res.part_1.unwrap().mean, Duration::from_nanos(74));
+            assert_eq!(res.part_1.unwrap().samples, 100000);
+            assert_eq!(res.part_2.unwrap().mean, Duration::from_nanos(74130000));
         }
 
         #[test]
@@ -231,9 +246,27 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.total, Duration::from_millis(2100));
+            assert_eq!(res.part_1.unwrap().mean, Duration::from_secs(2));
+            assert_eq!(res.part_2.unwrap().mean, Duration::from_millis(100));
+        }
+
+        #[test]
+        fn test_parse_step_and_memory() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (1.5µs ± 0.2µs @ 100 samples)".into(),
+                    "Part 1: 0 (10.0ms ± 1.0ms @ 100 samples)".into(),
+                    "Part 2: 10 (20.0ms ± 2.0ms @ 50 samples)".into(),
+                    "Peak memory: 2048 KiB".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.parse.unwrap().mean, Duration::from_nanos(1500));
+            assert_eq!(res.part_2.unwrap().stddev, Duration::from_millis(2));
+            assert_eq!(res.part_2.unwrap().samples, 50);
+            assert_eq!(res.peak_memory, Some(2048));
+            assert_eq!(res.total, Duration::from_nanos(30_001_500));
         }
 
         #[test]
@@ -246,7 +279,7 @@ mod child_commands {
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
+            assert_eq!(res.total, Duration::ZERO);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
@@ -0,0 +1,22 @@
+use std::process;
+
+use crate::template::{bench_history, terminal};
+use crate::{Calendar, Day, Year};
+
+pub fn handle_history(year: Year, day: Day) {
+    let entries = match bench_history::read(year, day) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    if entries.is_empty() {
+        println!("No benchmarks recorded for day {day} yet. Run `cargo time` or `cargo solve {day} --release --time` to record one.");
+        return;
+    }
+
+    let utc_offset = Calendar::for_year(year).unlock().utc_offset;
+    terminal::page(&bench_history::render(&entries, utc_offset));
+}
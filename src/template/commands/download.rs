@@ -1,15 +1,85 @@
-use crate::template::aoc_cli;
-use crate::Day;
-use std::process;
+use crate::template::commands::scaffold;
+use crate::template::{
+    answers, aoc_cli, datetime, get_module_path, puzzle, readme_progress, ANSI_BOLD, ANSI_RESET,
+};
+use crate::{Calendar, Day, Year};
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::{fs, process, thread, time::Duration};
 
-pub fn handle(day: Day) {
+/// Blocks until the puzzle for `day` unlocks, displaying a countdown in the meantime.
+fn wait_for_unlock(year: Year, day: Day) {
+    let unlock = datetime::unlock_timestamp(&Calendar::for_year(year), year, day);
+    let mut stdout = stdout();
+
+    loop {
+        let remaining = unlock - datetime::now_timestamp();
+        if remaining < 0 {
+            break;
+        }
+
+        print!(
+            "\r🎄 Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            datetime::format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+
+        thread::sleep(Duration::from_secs(1));
+    }
+
+    println!("\r🎄 Day {day} is unlocked!                    ");
+}
+
+/// Records the accepted answers contained in a puzzle description
+/// and updates the README progress if new answers were found.
+pub fn record_answers(year: Year, day: Day, markdown: &str) {
+    match answers::record(year, day, markdown) {
+        Ok(recorded) => {
+            for (part, answer) in &recorded {
+                println!("🎄 Recorded accepted answer for part {part}: {answer}");
+            }
+
+            if !recorded.is_empty() {
+                if let Err(e) = readme_progress::update(year) {
+                    eprintln!("failed to update README progress: {e}");
+                }
+            }
+        }
+        Err(e) => eprintln!("failed to record answers: {e}"),
+    }
+}
+
+pub fn handle(year: Year, day: Day, wait: bool, scaffold: bool) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if wait {
+        wait_for_unlock(year, day);
+    }
+
+    if let Err(e) = aoc_cli::download(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    match puzzle::download(year, day) {
+        Ok(path) => {
+            println!("🎄 Successfully wrote puzzle to \"{path}\".");
+            match fs::read_to_string(&path) {
+                Ok(markdown) => record_answers(year, day, &markdown),
+                Err(e) => eprintln!("failed to record answers: {e}"),
+            }
+        }
+        Err(e) => {
+            eprintln!("failed to download puzzle description: {e}");
+            process::exit(1);
+        }
+    }
+
+    if scaffold && !Path::new(&get_module_path(year, day)).exists() {
+        println!("---");
+        scaffold::handle(year, day);
+    }
 }
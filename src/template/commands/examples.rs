@@ -0,0 +1,49 @@
+use std::{fs, process};
+
+use crate::template::get_bin_name;
+use crate::template::{aoc_cli, examples};
+use crate::{Day, Part, Year};
+
+pub fn handle(year: Year, day: Day) {
+    let puzzle_path = aoc_cli::get_puzzle_path(year, day);
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!("Could not read \"{puzzle_path}\". Run `cargo download {day}` to download the puzzle first.");
+        process::exit(1);
+    };
+
+    let mut found = false;
+
+    for (part, example) in [Part::One, Part::Two]
+        .into_iter()
+        .zip(examples::extract(&markdown))
+    {
+        let Some(example) = example else {
+            continue;
+        };
+
+        found = true;
+
+        if let Err(e) = examples::write(year, day, part, &example) {
+            eprintln!("Failed to write example for part {part}: {e}");
+            process::exit(1);
+        }
+
+        println!(
+            "Created example file \"{}\" (expected answer: {})",
+            examples::get_example_path(year, day, part),
+            example.answer.as_deref().unwrap_or("unknown")
+        );
+    }
+
+    if !found {
+        eprintln!("No examples found in \"{puzzle_path}\".");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo test --bin {}` to test your solution against the examples.",
+        get_bin_name(year, day)
+    );
+}
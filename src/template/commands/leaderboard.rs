@@ -0,0 +1,79 @@
+use std::{
+    fs, process,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{aoc_web, get_data_dir, leaderboard, terminal};
+use crate::{Calendar, Day, Year};
+
+/// The leaderboard API should not be requested more often than every 15 minutes.
+const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+#[must_use]
+pub fn get_cache_path(year: Year, id: u64) -> String {
+    format!("{}/leaderboards/{id}.json", get_data_dir(year))
+}
+
+fn is_fresh(path: &str) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < CACHE_DURATION)
+}
+
+fn fetch(year: Year, id: u64) -> Result<String, String> {
+    let cache_path = get_cache_path(year, id);
+
+    if is_fresh(&cache_path) {
+        return fs::read_to_string(&cache_path).map_err(|e| e.to_string());
+    }
+
+    let json = aoc_web::fetch(&format!("/{year}/leaderboard/private/view/{id}.json"))
+        .map_err(|e| e.to_string())?;
+
+    fs::create_dir_all(format!("{}/leaderboards", get_data_dir(year)))
+        .and_then(|()| fs::write(&cache_path, &json))
+        .map_err(|e| format!("could not write cache file \"{cache_path}\": {e}"))?;
+
+    Ok(json)
+}
+
+/// Where a leaderboard is loaded from.
+pub enum Source {
+    /// The private leaderboard with this id, fetched from the API (or the cache).
+    Api(u64),
+    /// A previously downloaded leaderboard file.
+    File(String),
+}
+
+pub fn handle(year: Year, source: Source, day: Option<Day>) {
+    let json = match source {
+        Source::File(path) => fs::read_to_string(&path).map_err(|e| format!("{path}: {e}")),
+        Source::Api(id) => fetch(year, id),
+    };
+
+    let json = match json {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = match leaderboard::parse(&json) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to parse leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    let calendar = Calendar::for_year(year);
+    let table = match day {
+        Some(day) => leaderboard::render_day(&leaderboard, &calendar, day),
+        None => leaderboard::render(&leaderboard, &calendar),
+    };
+
+    terminal::page(&table);
+}
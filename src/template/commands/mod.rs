@@ -1,5 +1,9 @@
 pub mod all;
+pub mod bench;
 pub mod download;
+pub mod examples;
+pub mod leaderboard;
+pub mod progress;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
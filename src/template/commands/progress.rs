@@ -0,0 +1,14 @@
+use std::process;
+
+use crate::template::readme_progress;
+use crate::Year;
+
+pub fn handle(year: Year) {
+    match readme_progress::update(year) {
+        Ok(stars) => println!("🎄 Successfully updated README progress, {stars} stars collected."),
+        Err(e) => {
+            eprintln!("Failed to update README progress: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -1,16 +1,21 @@
-use std::process;
+use std::{cmp, fs, process};
 
-use crate::template::aoc_cli;
-use crate::Day;
+use crate::template::commands::download;
+use crate::template::{aoc_cli, puzzle, terminal};
+use crate::{Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(year: Year, day: Day) {
+    let path = aoc_cli::get_puzzle_path(year, day);
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let Ok(markdown) = fs::read_to_string(&path) else {
+        eprintln!(
+            "Could not read \"{path}\". Run `cargo download {day}` to download the puzzle first."
+        );
         process::exit(1);
     };
+
+    download::record_answers(year, day, &markdown);
+
+    let (cols, _) = terminal::size();
+    terminal::page(&puzzle::render(&markdown, cmp::min(cols, 100)));
 }
@@ -1,12 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::{aoc_cli, get_bin_name, get_module_path};
+use crate::{Day, Year};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<String> {
     None
@@ -19,35 +21,72 @@ pub fn part_two(input: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
+    use advent_of_code::template::read_example;
+    use advent_of_code::Part;
 
     #[test]
     fn test_part_one() {
-        const INPUT: &str = indoc! {""};
-        let result = part_one(INPUT);
-        assert_eq!(result, None);
+        let example = read_example(YEAR, DAY, Part::One);
+        let answer = example.answer.expect("missing expected answer of the example");
+        let result = part_one(&example.input);
+        assert_eq!(result, Some(answer));
     }
 
     #[test]
     fn test_part_two() {
-        const INPUT: &str = indoc! {""};
-        let result = part_two(INPUT);
-        assert_eq!(result, None);
+        let example = read_example(YEAR, DAY, Part::Two);
+        let answer = example.answer.expect("missing expected answer of the example");
+        let result = part_two(&example.input);
+        assert_eq!(result, Some(answer));
     }
 }
 "#;
 
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    create_parent_dir(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Solutions live in per-year directories which cargo does not discover on its own,
+/// so every solution binary is registered in the manifest.
+fn register_bin(year: Year, day: Day) -> Result<bool, std::io::Error> {
+    let manifest_path = "Cargo.toml";
+    let manifest = fs::read_to_string(manifest_path)?;
+    let name = get_bin_name(year, day);
+
+    if manifest.contains(&format!("name = \"{name}\"")) {
+        return Ok(false);
+    }
+
+    let entry = format!(
+        "\n[[bin]]\nname = \"{name}\"\npath = \"{}\"\n",
+        get_module_path(year, day)
+    );
+
+    let mut file = OpenOptions::new().append(true).open(manifest_path)?;
+    file.write_all(entry.as_bytes())?;
+    Ok(true)
+}
+
+pub fn handle(year: Year, day: Day) {
+    let input_path = aoc_cli::get_input_path(year, day);
+    let module_path = get_module_path(year, day);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -59,6 +98,7 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("YEAR_NUMBER", &year.to_string())
             .replace("DAY_NUMBER", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -71,6 +111,20 @@ pub fn handle(day: Day) {
         }
     }
 
+    match register_bin(year, day) {
+        Ok(true) => {
+            println!(
+                "Registered binary \"{}\" in \"Cargo.toml\"",
+                get_bin_name(year, day)
+            );
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register binary in Cargo.toml: {e}");
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -82,5 +136,5 @@ pub fn handle(day: Day) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
@@ -1,9 +1,17 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
+use crate::template::commands::all::child_commands;
+use crate::template::{
+    bench_history, get_bin_name, readme_benchmarks, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{Day, Part, PuzzleId, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(year: Year, day: Day, release: bool, time: bool, submit_part: Option<Part>) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if release {
         cmd_args.push("--release".to_string());
@@ -13,13 +21,20 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
-        cmd_args.push(submit_part.to_string());
+        cmd_args.push(PuzzleId::new(year, day, submit_part).to_string());
     }
 
     if time {
         cmd_args.push("--time".to_string());
     }
 
+    if time && release {
+        // benchmarked, optimized runs update the row of this day in the readme.
+        let output = child_commands::capture_output(Command::new("cargo").args(&cmd_args)).unwrap();
+        update_benchmarks(year, day, &output);
+        return;
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -29,3 +44,32 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+fn update_benchmarks(year: Year, day: Day, output: &[String]) {
+    let timings = child_commands::parse_exec_time(output, day);
+
+    if timings.part_1.is_none() && timings.part_2.is_none() {
+        return;
+    }
+
+    if let Err(e) = bench_history::record(year, std::slice::from_ref(&timings)) {
+        eprintln!("Failed to record benchmark history: {e}");
+    }
+
+    if !readme_benchmarks::tracks_year(year) {
+        println!(
+            "Skipped updating the README, its benchmark table holds the timings of another year."
+        );
+        return;
+    }
+
+    match readme_benchmarks::update_day(year, &timings) {
+        Ok(total_millis) => {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+            println!("Successfully updated README with benchmarks for day {day}.");
+        }
+        Err(e) => eprintln!("Failed to update readme with benchmarks: {e}"),
+    }
+}
@@ -0,0 +1,257 @@
+/// Module that loads the project configuration from `aoc.toml`.
+/// Every setting is optional, missing settings fall back to the defaults of the template.
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::{fs, io, process};
+
+use crate::template::readme_benchmarks::{self, Column, MARKER};
+use crate::template::readme_progress;
+use crate::Year;
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Toml(e) => write!(f, "{}", e.to_string().trim()),
+            Error::Invalid(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Error::Toml(e)
+    }
+}
+
+/// The project configuration.
+/// Paths are relative to the project root, `{year}` is replaced with the year of a command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The year commands use unless `--year` is passed.
+    pub year: Option<Year>,
+    /// The directory holding inputs, puzzles, examples and answers of a year.
+    pub data_dir: String,
+    /// The directory holding the solution modules of a year.
+    pub bin_dir: String,
+    /// The readme that benchmarks and progress are written to.
+    pub readme: String,
+    /// The marker that delimits the benchmark table in the readme.
+    pub benchmark_marker: String,
+    /// The optional columns of the benchmark table.
+    pub benchmark_columns: Vec<Column>,
+    /// The SVG file that a chart of the benchmarks is written to, if any.
+    pub benchmark_chart: Option<String>,
+    /// The marker that delimits the progress table in the readme.
+    pub progress_marker: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            data_dir: "data/{year}".into(),
+            bin_dir: "src/bin/{year}".into(),
+            readme: "README.md".into(),
+            benchmark_marker: MARKER.into(),
+            benchmark_columns: vec![],
+            benchmark_chart: None,
+            progress_marker: readme_progress::MARKER.into(),
+        }
+    }
+}
+
+/// Reads an optional string setting, rejecting values of other types.
+fn string(table: &toml::Table, key: &str, target: &mut String) -> Result<(), Error> {
+    match table.get(key) {
+        None => Ok(()),
+        Some(toml::Value::String(value)) => {
+            target.clone_from(value);
+            Ok(())
+        }
+        Some(_) => Err(Error::Invalid(format!("`{key}` must be a string."))),
+    }
+}
+
+/// Reads the optional columns of the benchmark table.
+fn columns(table: &toml::Table) -> Result<Vec<Column>, Error> {
+    let Some(value) = table.get("columns") else {
+        return Ok(vec![]);
+    };
+
+    let invalid = || Error::Invalid("`benchmarks.columns` must be a list of column names.".into());
+
+    let mut columns = value
+        .as_array()
+        .ok_or_else(invalid)?
+        .iter()
+        .map(|column| {
+            column
+                .as_str()
+                .ok_or_else(invalid)?
+                .parse()
+                .map_err(|e: readme_benchmarks::Error| Error::Invalid(e.to_string()))
+        })
+        .collect::<Result<Vec<Column>, Error>>()?;
+
+    columns.sort();
+    columns.dedup();
+    Ok(columns)
+}
+
+/// Returns the table of a section, rejecting settings that are not known.
+fn section<'a>(
+    table: &'a toml::Table,
+    name: &str,
+    keys: &[&str],
+) -> Result<Option<&'a toml::Table>, Error> {
+    let section = match table.get(name) {
+        None => return Ok(None),
+        Some(toml::Value::Table(section)) => section,
+        Some(_) => return Err(Error::Invalid(format!("`{name}` must be a table."))),
+    };
+
+    match section.keys().find(|key| !keys.contains(&key.as_str())) {
+        Some(key) => Err(Error::Invalid(format!("unknown setting `{name}.{key}`."))),
+        None => Ok(Some(section)),
+    }
+}
+
+impl Config {
+    /// Parses a configuration from the contents of a config file.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let table: toml::Table = s.parse()?;
+        let mut config = Config::default();
+
+        if let Some(key) = table
+            .keys()
+            .find(|key| !["year", "paths", "benchmarks", "progress"].contains(&key.as_str()))
+        {
+            return Err(Error::Invalid(format!("unknown setting `{key}`.")));
+        }
+
+        config.year = match table.get("year") {
+            None => None,
+            Some(toml::Value::Integer(year)) => Some(
+                u16::try_from(*year)
+                    .ok()
+                    .and_then(Year::new)
+                    .ok_or_else(|| Error::Invalid(format!("invalid year `{year}`.")))?,
+            ),
+            Some(_) => return Err(Error::Invalid("`year` must be a number.".into())),
+        };
+
+        if let Some(paths) = section(&table, "paths", &["data", "bin", "readme"])? {
+            string(paths, "data", &mut config.data_dir)?;
+            string(paths, "bin", &mut config.bin_dir)?;
+            string(paths, "readme", &mut config.readme)?;
+        }
+
+        if let Some(benchmarks) = section(&table, "benchmarks", &["marker", "columns", "chart"])? {
+            string(benchmarks, "marker", &mut config.benchmark_marker)?;
+            if benchmarks.contains_key("chart") {
+                let mut chart = String::new();
+                string(benchmarks, "chart", &mut chart)?;
+                config.benchmark_chart = Some(chart);
+            }
+            config.benchmark_columns = columns(benchmarks)?;
+        }
+
+        if let Some(progress) = section(&table, "progress", &["marker"])? {
+            string(progress, "marker", &mut config.progress_marker)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Loads the configuration from `path`, falling back to the defaults if the file does not exist.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Returns the data directory of `year`, e.g. `data/2020`.
+    #[must_use]
+    pub fn data_dir(&self, year: Year) -> String {
+        self.data_dir.replace("{year}", &year.to_string())
+    }
+
+    /// Returns the directory of the solution modules of `year`, e.g. `src/bin/2020`.
+    #[must_use]
+    pub fn bin_dir(&self, year: Year) -> String {
+        self.bin_dir.replace("{year}", &year.to_string())
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Returns the project configuration, loading it from `aoc.toml` on first access.
+/// Exits the process if the config file is invalid.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        Config::load(CONFIG_PATH).unwrap_or_else(|e| {
+            eprintln!("failed to load \"{CONFIG_PATH}\": {e}");
+            process::exit(1);
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Config;
+    use crate::template::readme_benchmarks::Column;
+    use crate::year;
+
+    #[test]
+    fn parses_config() {
+        let config = Config::parse(
+            "year = 2021\n\n[paths]\ndata = \"puzzles/{year}\"\nbin = \"src/bin/y{year}\"\n\n[benchmarks]\nmarker = \"<!-- bench -->\"\ncolumns = [\"link\", \"parse\"]\nchart = \"bench.svg\"\n\n[progress]\nmarker = \"<!-- stars -->\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(year!(2021)));
+        assert_eq!(config.data_dir(year!(2021)), "puzzles/2021");
+        assert_eq!(config.bin_dir(year!(2021)), "src/bin/y2021");
+        assert_eq!(config.readme, "README.md");
+        assert_eq!(config.benchmark_marker, "<!-- bench -->");
+        assert_eq!(config.benchmark_columns, [Column::Parse, Column::Link]);
+        assert_eq!(config.benchmark_chart.as_deref(), Some("bench.svg"));
+        assert_eq!(config.progress_marker, "<!-- stars -->");
+    }
+
+    #[test]
+    fn defaults_missing_settings() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(config, Config::default());
+        assert_eq!(config.data_dir(year!(2020)), "data/2020");
+        assert_eq!(config.bin_dir(year!(2020)), "src/bin/2020");
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(Config::parse("year = 1999").is_err());
+        assert!(Config::parse("year = \"2020\"").is_err());
+        assert!(Config::parse("[paths]\ndata = 1").is_err());
+        assert!(Config::parse("[paths]\ninputs = \"in\"").is_err());
+        assert!(Config::parse("years = 2020").is_err());
+        assert!(Config::parse("[benchmarks]\ncolumns = [\"speed\"]").is_err());
+    }
+}
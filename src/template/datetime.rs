@@ -0,0 +1,174 @@
+/// Minimal date handling in the timezone of an event.
+/// Timezones are fixed offsets from UTC, see [`crate::Unlock`]. Advent of Code runs on US eastern time,
+/// which has no daylight saving time in December.
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{Calendar, Day, Year};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A calendar date and time in the timezone of an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub year: i64,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Converts a unix timestamp to a date in the timezone `utc_offset` seconds from UTC,
+    /// e.g. `calendar.unlock().utc_offset` for the timezone of an event.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_timestamp(timestamp: i64, utc_offset: i64) -> Self {
+        let local = timestamp + utc_offset;
+        let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+        let seconds = local.rem_euclid(SECONDS_PER_DAY);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+        }
+    }
+
+    /// Returns the current date in the timezone `utc_offset` seconds from UTC.
+    #[must_use]
+    pub fn now(utc_offset: i64) -> Self {
+        Self::from_timestamp(now_timestamp(), utc_offset)
+    }
+}
+
+impl Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// Returns the current unix timestamp in seconds.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub fn now_timestamp() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Returns the unix timestamp at which the puzzle for `day` of `year` unlocks according to the unlock schedule of `calendar`,
+/// e.g. midnight of that day of December in the event timezone for advent of code.
+#[must_use]
+pub fn unlock_timestamp(calendar: &Calendar, year: Year, day: Day) -> i64 {
+    let unlock = calendar.unlock();
+    let days = days_from_civil(i64::from(year.into_inner()), unlock.month, day.into_inner());
+    days * SECONDS_PER_DAY + i64::from(unlock.hour) * 3600 - unlock.utc_offset
+}
+
+/// Formats a number of seconds as a countdown, e.g. `1d 02:03:04`.
+#[must_use]
+pub fn format_countdown(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let days = seconds / SECONDS_PER_DAY;
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds % SECONDS_PER_DAY / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// Converts a `(year, month, day)` triple to days since the unix epoch.
+/// see: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Converts days since the unix epoch to a `(year, month, day)` triple.
+/// see: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_countdown, unlock_timestamp, DateTime};
+    use crate::Calendar;
+    use crate::{day, year};
+
+    #[test]
+    fn converts_to_event_timezone() {
+        let offset = Calendar::ADVENT_OF_CODE.unlock().utc_offset;
+
+        // 2020-12-01T05:00:00Z is midnight in the event timezone.
+        let date = DateTime::from_timestamp(1_606_798_800, offset);
+        assert_eq!(date.to_string(), "2020-12-01 00:00:00");
+
+        let date = DateTime::from_timestamp(1_606_798_799, offset);
+        assert_eq!(date.to_string(), "2020-11-30 23:59:59");
+
+        let date = DateTime::from_timestamp(1_606_798_800, 0);
+        assert_eq!(date.to_string(), "2020-12-01 05:00:00");
+    }
+
+    #[test]
+    fn handles_leap_years() {
+        let date = DateTime::from_timestamp(951_782_400 + 12 * 3600, 0);
+        assert_eq!(date.to_string(), "2000-02-29 12:00:00");
+    }
+
+    #[test]
+    fn computes_unlock_time() {
+        assert_eq!(
+            unlock_timestamp(&Calendar::ADVENT_OF_CODE, year!(2020), day!(1)),
+            1_606_798_800
+        );
+        assert_eq!(
+            DateTime::from_timestamp(
+                unlock_timestamp(&Calendar::ADVENT_OF_CODE, year!(2023), day!(25)),
+                Calendar::ADVENT_OF_CODE.unlock().utc_offset
+            )
+            .to_string(),
+            "2023-12-25 00:00:00"
+        );
+    }
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(59), "00:00:59");
+        assert_eq!(format_countdown(3 * 3600 + 61), "03:01:01");
+        assert_eq!(format_countdown(86_400 + 1), "1d 00:00:01");
+        assert_eq!(format_countdown(-5), "00:00:00");
+    }
+}
@@ -0,0 +1,225 @@
+/// Module that extracts example inputs and their expected answers from puzzle descriptions.
+/// Examples are taken from code blocks following a paragraph containing "For example",
+/// expected answers from the last highlighted value of each part.
+use std::{fs, io};
+
+use crate::template::get_data_dir;
+use crate::{Day, Part, Year};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug)]
+enum Block {
+    Header(String),
+    Paragraph(String),
+    Code(String),
+}
+
+#[must_use]
+pub fn get_example_path(year: Year, day: Day, part: Part) -> String {
+    format!("{}/examples/{day}-{part}.txt", get_data_dir(year))
+}
+
+#[must_use]
+pub fn get_answer_path(year: Year, day: Day, part: Part) -> String {
+    format!("{}/examples/{day}-{part}.answer.txt", get_data_dir(year))
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match code.take() {
+                Some(code) => blocks.push(Block::Code(code)),
+                None => code = Some(String::new()),
+            }
+        } else if let Some(code) = code.as_mut() {
+            code.push_str(line);
+            code.push('\n');
+        } else if let Some(header) = line.strip_prefix("## ") {
+            blocks.push(Block::Header(header.to_string()));
+        } else if !line.trim().is_empty() {
+            blocks.push(Block::Paragraph(line.to_string()));
+        }
+    }
+
+    blocks
+}
+
+/// Returns all highlighted code spans (`` *`value`* ``) in a line of markdown.
+fn highlighted_values(line: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find("*`") {
+        let is_escaped = rest[..start].ends_with('\\');
+        rest = &rest[start + 2..];
+
+        if is_escaped {
+            continue;
+        }
+
+        let Some(end) = rest.find("`*") else {
+            break;
+        };
+
+        values.push(rest[..end].trim().to_string());
+        rest = &rest[end + 2..];
+    }
+
+    values
+}
+
+fn extract_part(blocks: &[Block]) -> Option<Example> {
+    let is_example_intro = |block: &Block| match block {
+        Block::Paragraph(p) => p.to_lowercase().contains("for example"),
+        _ => false,
+    };
+
+    let input = blocks
+        .windows(2)
+        .find_map(|pair| match pair {
+            [intro, Block::Code(code)] if is_example_intro(intro) => Some(code.clone()),
+            _ => None,
+        })
+        .or_else(|| {
+            blocks.iter().find_map(|block| match block {
+                Block::Code(code) => Some(code.clone()),
+                _ => None,
+            })
+        });
+
+    let answer = blocks.iter().rev().find_map(|block| match block {
+        Block::Paragraph(p) if !p.starts_with("Your puzzle answer was") => {
+            highlighted_values(p).pop()
+        }
+        _ => None,
+    });
+
+    if input.is_none() && answer.is_none() {
+        return None;
+    }
+
+    Some(Example {
+        input: input.unwrap_or_default(),
+        answer,
+    })
+}
+
+/// Extracts examples for both parts from a puzzle description.
+/// If part two does not introduce a new example input, the input of part one is reused.
+#[must_use]
+pub fn extract(markdown: &str) -> [Option<Example>; 2] {
+    let blocks = parse_blocks(markdown);
+
+    let part_two_start = blocks
+        .iter()
+        .position(|block| matches!(block, Block::Header(h) if h.contains("Part Two")))
+        .unwrap_or(blocks.len());
+
+    let part_one = extract_part(&blocks[..part_two_start]);
+    let mut part_two = extract_part(&blocks[part_two_start..]);
+
+    if let (Some(one), Some(two)) = (&part_one, &mut part_two) {
+        if two.input.is_empty() {
+            two.input = one.input.clone();
+        }
+    }
+
+    [part_one, part_two]
+}
+
+/// Writes an example and its expected answer to the example files of `day` and `part`.
+pub fn write(year: Year, day: Day, part: Part, example: &Example) -> Result<(), io::Error> {
+    fs::create_dir_all(format!("{}/examples", get_data_dir(year)))?;
+    fs::write(get_example_path(year, day, part), &example.input)?;
+
+    let answer_path = get_answer_path(year, day, part);
+    match &example.answer {
+        Some(answer) => fs::write(answer_path, format!("{answer}\n"))?,
+        None => {
+            if let Err(e) = fs::remove_file(answer_path) {
+                if e.kind() != io::ErrorKind::NotFound {
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the example for `day` and `part`.
+/// Missing example files result in an empty input without an expected answer.
+#[must_use]
+pub fn read(year: Year, day: Day, part: Part) -> Example {
+    let input = fs::read_to_string(get_example_path(year, day, part)).unwrap_or_default();
+    let answer = fs::read_to_string(get_answer_path(year, day, part))
+        .ok()
+        .map(|answer| answer.trim().to_string())
+        .filter(|answer| !answer.is_empty());
+    Example { input, answer }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, Example};
+
+    const PUZZLE: &str = r"## --- Day 1: Report Repair ---
+
+Find the two entries that sum to `2020`.
+
+For example, suppose your expense report contained the following:
+
+```
+1721
+979
+299
+```
+
+In this list, the two entries that sum to `2020` are `1721` and `299`. Multiplying them together produces `1721 * 299 = 514579`, so the correct answer is *`514579`*.
+
+Of course, your expense report is much larger. *Find the two entries that sum to `2020`; what do you get if you multiply them together?*
+
+Your puzzle answer was `1234`.
+
+## --- Part Two ---
+
+In your expense report, the product of the three entries that sum to `2020` is *`241861950`*.
+
+Your puzzle answer was `5678`.
+";
+
+    #[test]
+    fn extracts_both_parts() {
+        let [one, two] = extract(PUZZLE);
+        assert_eq!(
+            one,
+            Some(Example {
+                input: "1721\n979\n299\n".into(),
+                answer: Some("514579".into()),
+            })
+        );
+        assert_eq!(
+            two,
+            Some(Example {
+                input: "1721\n979\n299\n".into(),
+                answer: Some("241861950".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn prefers_code_block_after_example_intro() {
+        let md = "## --- Day 2 ---\n\n```\nnot it\n```\n\nFor example:\n\n```\nthis one\n```\n\nThe answer is *`3`*.\n";
+        let [one, two] = extract(md);
+        assert_eq!(one.unwrap().input, "this one\n");
+        assert_eq!(two, None);
+    }
+}
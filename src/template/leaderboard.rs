@@ -0,0 +1,334 @@
+/// Module that parses private leaderboard data and renders it as a table.
+/// The data format is the JSON exposed by the leaderboard API at
+/// `https://adventofcode.com/{year}/leaderboard/private/view/{id}.json`.
+use std::{cmp::Reverse, collections::BTreeMap, fmt::Display};
+
+use serde_json::Value;
+
+use crate::template::datetime::DateTime;
+use crate::{Calendar, Day};
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Format(String),
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid leaderboard JSON: {e}"),
+            Error::Format(field) => write!(f, "unexpected leaderboard format: {field}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Timestamps of the stars earned, by day and part.
+    pub completions: BTreeMap<Day, [Option<i64>; 2]>,
+}
+
+impl Member {
+    fn stars_on(&self, day: Day) -> usize {
+        self.completions
+            .get(&day)
+            .map_or(0, |parts| parts.iter().flatten().count())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: Vec<Member>,
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, Error> {
+    value.get(key).ok_or_else(|| Error::Format(key.into()))
+}
+
+fn parse_member(value: &Value) -> Result<Member, Error> {
+    let id = field(value, "id")?
+        .as_u64()
+        .ok_or_else(|| Error::Format("id".into()))?;
+
+    let name = match value.get("name").and_then(Value::as_str) {
+        Some(name) => name.to_string(),
+        None => format!("(anonymous user #{id})"),
+    };
+
+    let mut completions = BTreeMap::new();
+
+    if let Some(days) = field(value, "completion_day_level")?.as_object() {
+        for (day, parts) in days {
+            let day: Day = day
+                .parse()
+                .map_err(|_| Error::Format("completion_day_level".into()))?;
+
+            let star_ts = |part: &str| {
+                parts
+                    .get(part)
+                    .and_then(|p| p.get("get_star_ts"))
+                    .and_then(Value::as_i64)
+            };
+
+            completions.insert(day, [star_ts("1"), star_ts("2")]);
+        }
+    }
+
+    Ok(Member {
+        id,
+        name,
+        local_score: value
+            .get("local_score")
+            .and_then(Value::as_u64)
+            .unwrap_or(0),
+        stars: value.get("stars").and_then(Value::as_u64).unwrap_or(0),
+        completions,
+    })
+}
+
+/// Parses leaderboard API data. Members are sorted by local score, then by stars.
+pub fn parse(json: &str) -> Result<Leaderboard, Error> {
+    let value: Value = serde_json::from_str(json)?;
+
+    let event = match field(&value, "event")? {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    };
+
+    let mut members = field(&value, "members")?
+        .as_object()
+        .ok_or_else(|| Error::Format("members".into()))?
+        .values()
+        .map(parse_member)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    members.sort_by_key(|m| (Reverse(m.local_score), Reverse(m.stars), m.id));
+
+    Ok(Leaderboard { event, members })
+}
+
+fn pad(s: &str, width: usize) -> String {
+    let len = s.chars().count();
+    format!("{s}{}", " ".repeat(width.saturating_sub(len)))
+}
+
+/// Renders an overview of all members with their stars per day.
+/// `★` marks a completed day, `☆` a day where only the first part was completed.
+/// One column is rendered for every day of `calendar`.
+#[must_use]
+pub fn render(leaderboard: &Leaderboard, calendar: &Calendar) -> String {
+    let name_width = leaderboard
+        .members
+        .iter()
+        .map(|m| m.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let days_header: String = calendar
+        .days()
+        .map(|day| {
+            let n = day.into_inner();
+            if n < 10 {
+                ' '
+            } else {
+                char::from(b'0' + n / 10)
+            }
+        })
+        .collect();
+    let days_units: String = calendar
+        .days()
+        .map(|day| char::from(b'0' + day.into_inner() % 10))
+        .collect();
+
+    let mut lines = vec![
+        format!("{}{days_header}", " ".repeat(name_width + 8)),
+        format!(
+            "{:>3}  {}   {}  {:>5} {:>5}",
+            "#",
+            pad("Name", name_width),
+            days_units,
+            "Score",
+            "Stars"
+        ),
+    ];
+
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let stars: String = calendar
+            .days()
+            .map(|day| match member.stars_on(day) {
+                2 => '★',
+                1 => '☆',
+                _ => '·',
+            })
+            .collect();
+
+        lines.push(format!(
+            "{:>3}) {}   {}  {:>5} {:>5}",
+            rank + 1,
+            pad(&member.name, name_width),
+            stars,
+            member.local_score,
+            member.stars
+        ));
+    }
+
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the star timestamps of all members for a single day, ordered by completion time.
+#[must_use]
+pub fn render_day(leaderboard: &Leaderboard, calendar: &Calendar, day: Day) -> String {
+    let name_width = leaderboard
+        .members
+        .iter()
+        .map(|m| m.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut completions: Vec<(&Member, [Option<i64>; 2])> = leaderboard
+        .members
+        .iter()
+        .filter_map(|m| m.completions.get(&day).map(|parts| (m, *parts)))
+        .collect();
+
+    // members that completed both parts first, then by time of the last star.
+    completions.sort_by_key(|(m, [one, two])| (two.is_none(), two.or(*one), m.id));
+
+    let format_ts = |ts: Option<i64>| {
+        ts.map_or_else(
+            || "-".to_string(),
+            |ts| DateTime::from_timestamp(ts, calendar.unlock().utc_offset).to_string(),
+        )
+    };
+
+    let mut lines = vec![format!(
+        "{:>3}  {}  {:<19}  {:<19}",
+        "#",
+        pad("Name", name_width),
+        "Part 1",
+        "Part 2"
+    )];
+
+    for (rank, (member, [one, two])) in completions.iter().enumerate() {
+        lines.push(format!(
+            "{:>3}) {}  {:<19}  {:<19}",
+            rank + 1,
+            pad(&member.name, name_width),
+            format_ts(*one),
+            format_ts(*two)
+        ));
+    }
+
+    if completions.is_empty() {
+        lines.push(format!("No stars collected on day {day} yet."));
+    }
+
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, render, render_day};
+    use crate::{day, year, Calendar, Unlock};
+
+    const JSON: &str = r#"{
+        "owner_id": 1,
+        "event": "2020",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+                "last_star_ts": 1606885200,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1606798860, "star_index": 0 },
+                           "2": { "get_star_ts": 1606799100, "star_index": 1 } },
+                    "2": { "1": { "get_star_ts": 1606885200, "star_index": 2 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 12, "global_score": 0,
+                "last_star_ts": 1606798920,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1606798810, "star_index": 0 },
+                           "2": { "get_star_ts": 1606798920, "star_index": 1 } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = parse(JSON).unwrap();
+        assert_eq!(leaderboard.event, "2020");
+        assert_eq!(leaderboard.members.len(), 2);
+        assert_eq!(leaderboard.members[0].name, "(anonymous user #2)");
+        assert_eq!(leaderboard.members[1].name, "Alice");
+        assert_eq!(
+            leaderboard.members[1].completions.get(&day!(2)),
+            Some(&[Some(1_606_885_200), None])
+        );
+    }
+
+    #[test]
+    fn renders_a_column_per_calendar_day() {
+        let leaderboard = parse(JSON).unwrap();
+
+        let lines: Vec<_> = render(&leaderboard, &Calendar::for_year(year!(2025)))
+            .lines()
+            .map(String::from)
+            .collect();
+        assert!(lines[1].contains(" 123456789012 "));
+        assert!(lines[3].contains(" ★☆·········· "));
+
+        let lines: Vec<_> = render(&leaderboard, &Calendar::for_year(year!(2020)))
+            .lines()
+            .map(String::from)
+            .collect();
+        assert!(lines[1].contains(" 1234567890123456789012345 "));
+    }
+
+    #[test]
+    fn renders_day() {
+        let leaderboard = parse(JSON).unwrap();
+        let expected = [
+            "  #  Name                 Part 1               Part 2",
+            "  1) (anonymous user #2)  2020-12-01 00:00:10  2020-12-01 00:02:00",
+            "  2) Alice                2020-12-01 00:01:00  2020-12-01 00:05:00",
+        ]
+        .join("\n");
+        let calendar = Calendar::for_year(year!(2020));
+        assert_eq!(render_day(&leaderboard, &calendar, day!(1)), expected);
+
+        let utc = Calendar::new(
+            25,
+            2,
+            1,
+            Unlock {
+                utc_offset: 0,
+                ..calendar.unlock()
+            },
+        );
+        assert!(render_day(&leaderboard, &utc, day!(1)).contains("2020-12-01 05:00:10"));
+    }
+}
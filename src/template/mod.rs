@@ -1,36 +1,144 @@
-use crate::Day;
+use crate::{Day, Part, Year};
 use std::{env, fs};
 
+pub mod answers;
 pub mod aoc_cli;
+pub mod aoc_web;
+pub mod bench_history;
 pub mod commands;
+pub mod config;
+pub mod datetime;
+pub mod examples;
+pub mod leaderboard;
+pub mod puzzle;
 pub mod readme_benchmarks;
+pub mod readme_progress;
 pub mod runner;
+pub mod submissions;
+pub mod terminal;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Returns the year configured via the `AOC_YEAR` environment variable or the project config.
+/// Commands use this year unless a `--year` is passed explicitly.
+#[must_use]
+pub fn default_year() -> Option<Year> {
+    match env::var("AOC_YEAR") {
+        Ok(year) => year.parse().ok(),
+        Err(_) => config::get().year,
+    }
+}
+
+impl Day {
+    /// Returns the current day of the event of the configured year (see [`default_year`]),
+    /// returns [`None`] if no year is configured or the event is not running.
+    #[must_use]
+    pub fn today() -> Option<Self> {
+        Self::today_of(default_year()?)
+    }
+}
+
+/// Returns the data directory of a year, e.g. `data/2020`.
+#[must_use]
+pub fn get_data_dir(year: Year) -> String {
+    config::get().data_dir(year)
+}
+
+/// Returns the name of the solution binary of a day, e.g. `2020-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
+/// Returns the path of the solution module of a day, e.g. `src/bin/2020/01.rs`.
+#[must_use]
+pub fn get_module_path(year: Year, day: Day) -> String {
+    format!("{}/{day}.rs", config::get().bin_dir(year))
+}
+
+/// Returns the path of the solution module of a day relative to the project root, e.g. `./src/bin/2020/01.rs`.
+#[must_use]
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./{}", get_module_path(year, day))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the example for one part of a day.
+/// Examples are extracted from puzzle descriptions with `cargo examples <day>`.
+///
+/// # Panics
+/// Panics if the example input of the part is missing, so that tests do not pass on an empty example.
+#[must_use]
+pub fn read_example(year: Year, day: Day, part: Part) -> examples::Example {
+    let example = examples::read(year, day, part);
+    assert!(
+        !example.input.is_empty(),
+        "could not open example file \"{}\", run `cargo examples {day}` first",
+        examples::get_example_path(year, day, part)
+    );
+    example
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// Solutions receive the input either as `&str` or as `&Input`, depending on their signature.
+/// If a parse function is passed as third argument, the input is parsed once (and timed separately)
+/// and both parts receive a reference to the parsed input.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($year:expr, $day:expr, $parse:path) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
+        /// The current day.
+        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            use advent_of_code::{Part, PuzzleId};
+            let input = advent_of_code::Input::new(advent_of_code::template::read_file(
+                "inputs", YEAR, DAY,
+            ));
+            let parsed = run_parse($parse, (&input).into());
+            run_part(part_one, &parsed, PuzzleId::new(YEAR, DAY, Part::One));
+            run_part(part_two, &parsed, PuzzleId::new(YEAR, DAY, Part::Two));
+            print_peak_memory();
+        }
+    };
+    ($year:expr, $day:expr) => {
+        /// The current year.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            use advent_of_code::{Part, PuzzleId};
+            let input = advent_of_code::Input::new(advent_of_code::template::read_file(
+                "inputs", YEAR, DAY,
+            ));
+            run_part(
+                part_one,
+                (&input).into(),
+                PuzzleId::new(YEAR, DAY, Part::One),
+            );
+            run_part(
+                part_two,
+                (&input).into(),
+                PuzzleId::new(YEAR, DAY, Part::Two),
+            );
+            print_peak_memory();
         }
     };
 }
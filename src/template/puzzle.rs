@@ -0,0 +1,618 @@
+/// Module that converts puzzle pages to markdown and renders them to the terminal.
+/// Only the subset of HTML used by adventofcode.com puzzle descriptions is supported.
+use std::{fmt::Display, fs, path::Path};
+
+use crate::template::{aoc_cli, aoc_web, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Web(aoc_web::AocWebError),
+    MissingDescription,
+    IO(std::io::Error),
+}
+
+impl From<aoc_web::AocWebError> for Error {
+    fn from(e: aoc_web::AocWebError) -> Self {
+        Error::Web(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Web(e) => write!(f, "{e}"),
+            Error::MissingDescription => write!(f, "no puzzle description found in page."),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Downloads the puzzle page for `day` and writes its description as markdown to the puzzle path.
+pub fn download(year: Year, day: Day) -> Result<String, Error> {
+    let html = aoc_web::fetch(&aoc_web::puzzle_url(year, day))?;
+    let markdown = html_to_markdown(&html).ok_or(Error::MissingDescription)?;
+    let path = aoc_cli::get_puzzle_path(year, day);
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, markdown)?;
+    Ok(path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug)]
+enum Node {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+impl Node {
+    fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Node::Element { children, .. } => children.iter().map(Node::text).collect(),
+            Node::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// An element that has been opened but not closed yet: name, attributes and children.
+type OpenElement = (String, Vec<(String, String)>, Vec<Node>);
+
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "link", "meta"];
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                e if e.starts_with("#x") || e.starts_with("#X") => u32::from_str_radix(&e[2..], 16)
+                    .ok()
+                    .and_then(char::from_u32),
+                e if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+                _ => None,
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>) {
+    let tag = tag.trim_end_matches('/').trim();
+    let (name, mut rest) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    let mut attrs = vec![];
+
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_lowercase();
+        let value_start = rest[eq + 1..].trim_start();
+
+        let (value, remainder) = match value_start.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value_start = &value_start[1..];
+                let end = value_start.find(quote).unwrap_or(value_start.len());
+                (
+                    &value_start[..end],
+                    value_start.get(end + 1..).unwrap_or(""),
+                )
+            }
+            _ => {
+                let end = value_start
+                    .find(char::is_whitespace)
+                    .unwrap_or(value_start.len());
+                (&value_start[..end], &value_start[end..])
+            }
+        };
+
+        attrs.push((key, decode_entities(value)));
+        rest = remainder;
+    }
+
+    (name.to_lowercase(), attrs)
+}
+
+/// Parses `html` into a list of nodes. Unclosed elements are closed implicitly.
+fn parse_html(html: &str) -> Vec<Node> {
+    // stack of open elements, the root is a pseudo element.
+    let mut stack: Vec<OpenElement> = vec![(String::new(), vec![], vec![])];
+    let mut rest = html;
+
+    fn close(stack: &mut Vec<OpenElement>) {
+        if let Some((name, attrs, children)) = stack.pop() {
+            if let Some(parent) = stack.last_mut() {
+                parent.2.push(Node::Element {
+                    name,
+                    attrs,
+                    children,
+                });
+            }
+        }
+    }
+
+    while !rest.is_empty() {
+        let Some(pos) = rest.find('<') else {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(Node::Text(decode_entities(rest)));
+            break;
+        };
+
+        if pos > 0 {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(Node::Text(decode_entities(&rest[..pos])));
+        }
+
+        rest = &rest[pos..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            if let Some(idx) = stack.iter().rposition(|(n, _, _)| *n == name) {
+                while stack.len() > idx {
+                    close(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let (name, attrs) = parse_tag(tag);
+
+        if VOID_ELEMENTS.contains(&name.as_str()) || tag.ends_with('/') {
+            stack.last_mut().unwrap().2.push(Node::Element {
+                name,
+                attrs,
+                children: vec![],
+            });
+        } else if name == "script" || name == "style" {
+            // skip raw text elements entirely.
+            let closing = format!("</{name}");
+            rest = rest.find(&closing).map_or("", |end| &rest[end..]);
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else {
+            stack.push((name, attrs, vec![]));
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    stack
+        .pop()
+        .map(|(_, _, children)| children)
+        .unwrap_or_default()
+}
+
+fn escape_markdown(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut acc, c| {
+            if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+                acc.push('\\');
+            }
+            acc.push(c);
+            acc
+        })
+}
+
+fn code_span(code: &str) -> String {
+    if code.contains('`') {
+        format!("`` {code} ``")
+    } else {
+        format!("`{code}`")
+    }
+}
+
+fn inline(nodes: &[Node]) -> String {
+    let mut out = String::new();
+
+    for node in nodes {
+        match node {
+            Node::Text(text) => {
+                let normalized = text.replace(char::is_whitespace, " ");
+                out.push_str(&escape_markdown(&normalized));
+            }
+            Node::Element { name, children, .. } => match name.as_str() {
+                "em" | "strong" | "b" | "i" => {
+                    let inner = inline(children);
+                    if !inner.trim().is_empty() {
+                        out.push_str(&format!("*{}*", inner.trim()));
+                    }
+                }
+                "code" => {
+                    // AoC highlights answers with `<code><em>..</em></code>`.
+                    let is_highlighted = matches!(
+                        children.as_slice(),
+                        [Node::Element { name, .. }] if name == "em"
+                    );
+                    let span = code_span(&node.text());
+                    if is_highlighted {
+                        out.push_str(&format!("*{span}*"));
+                    } else {
+                        out.push_str(&span);
+                    }
+                }
+                "a" => {
+                    let inner = inline(children);
+                    match node.attr("href") {
+                        Some(href) => {
+                            out.push_str(&format!("[{}]({})", inner.trim(), aoc_web::url(href)));
+                        }
+                        None => out.push_str(&inner),
+                    }
+                }
+                "br" => out.push('\n'),
+                _ => out.push_str(&inline(children)),
+            },
+        }
+    }
+
+    // collapse whitespace introduced at node boundaries.
+    let mut collapsed = String::with_capacity(out.len());
+    for c in out.chars() {
+        if c == ' ' && collapsed.ends_with(' ') {
+            continue;
+        }
+        collapsed.push(c);
+    }
+    collapsed
+}
+
+fn blocks(nodes: &[Node], out: &mut Vec<String>) {
+    for node in nodes {
+        let Node::Element { name, children, .. } = node else {
+            let text = inline(std::slice::from_ref(node));
+            if !text.trim().is_empty() {
+                out.push(text.trim().to_string());
+            }
+            continue;
+        };
+
+        match name.as_str() {
+            "h1" | "h2" | "h3" => out.push(format!("## {}", inline(children).trim())),
+            "pre" => {
+                let mut code = node.text();
+                if !code.ends_with('\n') {
+                    code.push('\n');
+                }
+                out.push(format!("```\n{code}```"));
+            }
+            "ul" | "ol" => {
+                let items: Vec<String> = children
+                    .iter()
+                    .filter_map(|child| match child {
+                        Node::Element { name, children, .. } if name == "li" => {
+                            Some(format!("- {}", inline(children).trim()))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                out.push(items.join("\n"));
+            }
+            "article" | "main" | "div" | "section" => blocks(children, out),
+            _ => {
+                let text = inline(children);
+                if !text.trim().is_empty() {
+                    out.push(text.trim().to_string());
+                }
+            }
+        }
+    }
+}
+
+fn collect_description(nodes: &[Node], out: &mut Vec<String>) {
+    for node in nodes {
+        let Node::Element { name, children, .. } = node else {
+            continue;
+        };
+
+        if name == "article" {
+            blocks(children, out);
+        } else if name == "p"
+            && node
+                .text()
+                .trim_start()
+                .starts_with("Your puzzle answer was")
+        {
+            out.push(inline(children).trim().to_string());
+        } else {
+            collect_description(children, out);
+        }
+    }
+}
+
+/// Converts the HTML of a puzzle page to markdown.
+/// The result contains the description of each part, as well as any accepted answers.
+/// Returns [`None`] if the page does not contain a puzzle description.
+#[must_use]
+pub fn html_to_markdown(html: &str) -> Option<String> {
+    let nodes = parse_html(html);
+    let mut out = vec![];
+    collect_description(&nodes, &mut out);
+
+    if out.is_empty() {
+        return None;
+    }
+
+    Some(out.join("\n\n") + "\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::new();
+        if self.emphasis {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.code {
+            s.push_str(ANSI_ITALIC);
+        }
+        s
+    }
+}
+
+/// Splits a line of markdown into styled characters, stripping markup and link targets.
+fn styled_chars(line: &str) -> Vec<(char, Style)> {
+    let mut out = vec![];
+    let mut style = Style::default();
+    let mut chars = line.chars().peekable();
+    let mut in_link = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !style.code => {
+                if let Some(next) = chars.next() {
+                    out.push((next, style));
+                }
+            }
+            '`' => {
+                // skip the padding of double backtick spans.
+                while chars.peek() == Some(&'`') {
+                    chars.next();
+                }
+                style.code = !style.code;
+            }
+            '*' if !style.code => style.emphasis = !style.emphasis,
+            '[' if !style.code => in_link = true,
+            ']' if !style.code && in_link && chars.peek() == Some(&'(') => {
+                in_link = false;
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            c => out.push((c, style)),
+        }
+    }
+
+    out
+}
+
+/// Word-wraps styled text at `width`, prefixing continuation lines with `indent`.
+fn wrap(text: &[(char, Style)], width: usize, prefix: &str, indent: &str) -> Vec<String> {
+    let words = text
+        .split(|(c, _)| *c == ' ')
+        .filter(|word| !word.is_empty());
+
+    let mut lines = vec![];
+    let mut line = prefix.to_string();
+    let mut line_width = prefix.chars().count();
+    let mut is_empty = true;
+
+    for word in words {
+        let word_width = word.len();
+
+        if !is_empty && line_width + 1 + word_width > width {
+            lines.push(line);
+            line = indent.to_string();
+            line_width = indent.chars().count();
+            is_empty = true;
+        }
+
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+
+        let mut current = Style::default();
+        for &(c, style) in word {
+            if style != current {
+                line.push_str(ANSI_RESET);
+                line.push_str(&style.ansi());
+                current = style;
+            }
+            line.push(c);
+        }
+        if current != Style::default() {
+            line.push_str(ANSI_RESET);
+        }
+
+        line_width += word_width;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Renders puzzle markdown for display in a terminal with a maximum line length of `width`.
+/// Emphasis and headers are displayed bold, inline code and code blocks are displayed italic.
+#[must_use]
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut lines = vec![];
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push(format!("    {ANSI_ITALIC}{line}{ANSI_RESET}"));
+        } else if let Some(header) = line.strip_prefix("## ") {
+            let header: String = styled_chars(header).into_iter().map(|(c, _)| c).collect();
+            lines.push(format!("{ANSI_BOLD}{header}{ANSI_RESET}"));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            lines.extend(wrap(&styled_chars(item), width, "  • ", "    "));
+        } else if line.trim().is_empty() {
+            lines.push(String::new());
+        } else {
+            lines.extend(wrap(&styled_chars(line), width, "", ""));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{html_to_markdown, render};
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2020</title><script>var x = "<article>";</script></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Report Repair ---</h2><p>Find the two entries that sum to <code>2020</code>; what do you get if you <em>multiply them together</em>?</p>
+<p>For example, suppose your expense report contained the following:</p>
+<pre><code>1721
+979
+</code></pre>
+<p>In this list, the two entries that sum to <code>2020</code> are <code>1721</code> and <code>299</code>. Multiplying them together produces <code>1721 * 299 = <em>514579</em></code>, so the correct answer is <code><em>514579</em></code>.</p>
+<ul><li>A <a href="/2020/about">link</a> &amp; an item.</li></ul>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<p>You can also <a href="share">[Share]</a> this puzzle.</p>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn converts_description() {
+        let md = html_to_markdown(PAGE).unwrap();
+        let expected = [
+            "## --- Day 1: Report Repair ---",
+            "",
+            "Find the two entries that sum to `2020`; what do you get if you *multiply them together*?",
+            "",
+            "For example, suppose your expense report contained the following:",
+            "",
+            "```",
+            "1721",
+            "979",
+            "```",
+            "",
+            "In this list, the two entries that sum to `2020` are `1721` and `299`. Multiplying them together produces `1721 * 299 = 514579`, so the correct answer is *`514579`*.",
+            "",
+            "- A [link](https://adventofcode.com/2020/about) & an item.",
+            "",
+            "Your puzzle answer was `1234`.",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(md, expected);
+    }
+
+    #[test]
+    fn returns_none_without_description() {
+        assert!(html_to_markdown("<html><body><p>404 Not Found</p></body></html>").is_none());
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let md = "## --- Part Two ---\n\nThe answer is *`42`*.\n\n```\n1 * 2\n```\n";
+        let rendered = render(md, 80);
+        let expected = [
+            format!("{ANSI_BOLD}--- Part Two ---{ANSI_RESET}"),
+            String::new(),
+            format!("The answer is {ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}42{ANSI_RESET}."),
+            String::new(),
+            format!("    {ANSI_ITALIC}1 * 2{ANSI_RESET}"),
+        ]
+        .join("\n");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn wraps_paragraphs() {
+        let rendered = render("aaa bbb ccc ddd", 8);
+        assert_eq!(rendered, "aaa bbb\nccc ddd");
+    }
+}
@@ -1,15 +1,39 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Component, Path};
+use std::str::FromStr;
+use std::time::Duration;
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::config::{self, Config};
+use crate::template::runner::Measurement;
+use crate::template::{aoc_web, default_year, get_path_for_bin};
+use crate::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub mod chart;
+
+/// The default marker that delimits the benchmark table, see [`Config::benchmark_marker`].
+pub const MARKER: &str = "<!--- benchmarking table --->";
 
 #[derive(Debug)]
 pub enum Error {
     Parser(String),
     IO(io::Error),
+    #[cfg(feature = "serde")]
+    Json(serde_json::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{msg}"),
+            Error::IO(e) => write!(f, "{e}"),
+            #[cfg(feature = "serde")]
+            Error::Json(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -18,26 +42,124 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[derive(Clone)]
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+/// The execution times of the parts of a day.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Timings {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// The parse step of solutions that parse their input separately.
+    pub parse: Option<Measurement>,
+    pub part_1: Option<Measurement>,
+    pub part_2: Option<Measurement>,
+    /// The peak memory usage of the solution in KiB.
+    pub peak_memory: Option<u64>,
+    pub total: Duration,
 }
 
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+/// Optional columns of the benchmark table, selected via `benchmarks.columns` in the project config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Column {
+    /// The time of the parse step.
+    Parse,
+    /// The number of samples of both parts.
+    Samples,
+    /// The standard deviation of both parts.
+    Stddev,
+    /// The peak memory usage.
+    Memory,
+    /// The change of the time of both parts compared to the previous table.
+    Delta,
+    /// A link to the puzzle page.
+    Link,
 }
 
+impl FromStr for Column {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "samples" => Ok(Column::Samples),
+            "stddev" => Ok(Column::Stddev),
+            "memory" => Ok(Column::Memory),
+            "delta" => Ok(Column::Delta),
+            "link" => Ok(Column::Link),
+            _ => Err(Error::Parser(format!(
+                "unknown benchmark column `{s}`, expecting one of parse, samples, stddev, memory, delta, link."
+            ))),
+        }
+    }
+}
+
+/// Parses a duration in the format of [`Duration`]'s debug output, e.g. `74.1µs`.
+/// for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let parse_to_float =
+        |s: &str, postfix: &str| s.split(postfix).next()?.trim().parse::<f64>().ok();
+
+    let nanos = match s.trim() {
+        s if s.contains("ns") => parse_to_float(s, "ns"),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }?;
+
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Formats a duration the same way the runner displays it, e.g. `74.1µs`.
+fn format_duration(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "-".into(), |d| format!("{d:.1?}"))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_memory(kib: u64) -> String {
+    if kib >= 1024 {
+        format!("{:.1} MiB", kib as f64 / 1024_f64)
+    } else {
+        format!("{kib} KiB")
+    }
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Formats a value of both parts, e.g. `100 / 50`.
+fn format_parts(timing: &Timings, format: impl Fn(&Measurement) -> String) -> String {
+    [&timing.part_1, &timing.part_2]
+        .map(|m| m.as_ref().map_or_else(|| "-".into(), &format))
+        .join(" / ")
+}
+
+/// The combined time of both parts, which is compared to the previous table.
+fn parts_time(timing: &Timings) -> Option<Duration> {
+    Some(timing.part_1?.mean + timing.part_2?.mean)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_delta(current: Option<Duration>, previous: Option<Duration>) -> String {
+    match (current, previous) {
+        (Some(current), Some(previous)) if !previous.is_zero() => {
+            let delta = (current.as_nanos() as f64 / previous.as_nanos() as f64 - 1_f64) * 100_f64;
+            format!("{delta:+.1}%")
+        }
+        _ => "-".into(),
+    }
+}
+
+pub struct TablePosition {
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
+}
+
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,74 +180,367 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Splits a table row into its cells, removing code formatting.
+fn cells(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_matches('|')
+        .split('|')
+        .map(|cell| cell.trim().trim_matches('`').to_string())
+        .collect()
+}
+
+/// A previously generated table.
+struct ParsedTable {
+    header: Vec<String>,
+    rows: BTreeMap<Day, String>,
+}
+
+impl ParsedTable {
+    fn parse(table: &str) -> Self {
+        let mut lines = table.lines().skip_while(|line| !line.starts_with("| Day"));
+        let header = lines.next().map(cells).unwrap_or_default();
+
+        let rows = lines
+            .filter_map(|line| {
+                let day: String = cells(line)
+                    .first()?
+                    .trim_start_matches('[')
+                    .strip_prefix("Day ")?
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect();
+                Some((Day::new(day.parse().ok()?)?, line.to_string()))
+            })
+            .collect();
+
+        Self { header, rows }
+    }
+
+    /// Reads the duration in the column `name` of a row.
+    fn duration(&self, row: &str, name: &str) -> Option<Duration> {
+        let position = self.header.iter().position(|cell| cell == name)?;
+        parse_duration(cells(row).get(position)?)
+    }
+
+    /// The combined time of both parts of a row.
+    fn parts_time(&self, row: &str) -> Option<Duration> {
+        Some(self.duration(row, "Part 1")? + self.duration(row, "Part 2")?)
+    }
+
+    /// Reads the part times of every row.
+    fn timings(&self) -> Vec<Timings> {
+        let measurement = |row: &str, name: &str| {
+            self.duration(row, name).map(|mean| Measurement {
+                mean,
+                stddev: Duration::ZERO,
+                samples: 1,
+            })
+        };
+
+        self.rows
+            .iter()
+            .map(|(day, row)| Timings {
+                day: *day,
+                parse: measurement(row, "Parse"),
+                part_1: measurement(row, "Part 1"),
+                part_2: measurement(row, "Part 2"),
+                peak_memory: None,
+                total: self.total(row),
+            })
+            .collect()
+    }
+
+    /// The total time of a row, including the parse step.
+    fn total(&self, row: &str) -> Duration {
+        ["Parse", "Part 1", "Part 2"]
+            .iter()
+            .filter_map(|name| self.duration(row, name))
+            .sum()
+    }
+}
+
+fn table_header(config: &Config) -> Vec<String> {
+    let has = |column: Column| config.benchmark_columns.contains(&column);
+
+    let mut header = vec!["Day"];
+    if has(Column::Parse) {
+        header.push("Parse");
+    }
+    header.extend(["Part 1", "Part 2"]);
+    for (column, name) in [
+        (Column::Samples, "Samples"),
+        (Column::Stddev, "Std. dev."),
+        (Column::Memory, "Peak memory"),
+        (Column::Delta, "Δ"),
+        (Column::Link, "Puzzle"),
+    ] {
+        if has(column) {
+            header.push(name);
+        }
+    }
+
+    header.into_iter().map(String::from).collect()
+}
+
+fn format_row(config: &Config, year: Year, timing: &Timings, previous: Option<Duration>) -> String {
+    let has = |column: Column| config.benchmark_columns.contains(&column);
+
+    let path = get_path_for_bin(year, timing.day);
+    let mut row = vec![format!("[Day {}]({})", timing.day.into_inner(), path)];
+
+    if has(Column::Parse) {
+        row.push(format!(
+            "`{}`",
+            format_duration(timing.parse.map(|m| m.mean))
+        ));
+    }
+    row.push(format!(
+        "`{}`",
+        format_duration(timing.part_1.map(|m| m.mean))
+    ));
+    row.push(format!(
+        "`{}`",
+        format_duration(timing.part_2.map(|m| m.mean))
+    ));
+    if has(Column::Samples) {
+        row.push(format_parts(timing, |m| m.samples.to_string()));
+    }
+    if has(Column::Stddev) {
+        row.push(format_parts(timing, |m| format!("`±{:.1?}`", m.stddev)));
+    }
+    if has(Column::Memory) {
+        row.push(timing.peak_memory.map_or_else(|| "-".into(), format_memory));
+    }
+    if has(Column::Delta) {
+        row.push(format_delta(parts_time(timing), previous));
+    }
+    if has(Column::Link) {
+        row.push(format!(
+            "[Puzzle]({})",
+            aoc_web::puzzle_url(year, timing.day)
+        ));
+    }
+
+    format!("| {} |", row.join(" | "))
+}
+
+/// Returns the path of `target` relative to the directory of `file`, where both are relative to the project root.
+fn relative_link(file: &str, target: &str) -> String {
+    let components = |path: &str| -> Vec<String> {
+        Path::new(path)
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let mut directory = components(file);
+    directory.pop();
+    let target = components(target);
+    let common = directory
+        .iter()
+        .zip(&target)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut link = vec![".."; directory.len() - common];
+    link.extend(target[common..].iter().map(String::as_str));
+    link.join("/")
+}
+
+fn format_table(prefix: &str, config: &Config, rows: Vec<String>, total_millis: f64) -> String {
+    let header = table_header(config);
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
+        config.benchmark_marker.clone(),
+        format!("{prefix} Benchmarks"),
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", header.join(" | ")),
+        format!("| {} |", vec![":---:"; header.len()].join(" | ")),
     ];
 
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
-    }
+    lines.extend(rows);
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    if let Some(chart) = &config.benchmark_chart {
+        lines.push(String::new());
+        lines.push(format!(
+            "![Benchmark chart]({})",
+            relative_link(&config.readme, chart)
+        ));
+    }
+    lines.push(config.benchmark_marker.clone());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    config: &Config,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let positions = locate_table(s, &config.benchmark_marker)?;
+    let previous = ParsedTable::parse(&s[positions.pos_start..positions.pos_end]);
+
+    let rows = timings
+        .iter()
+        .map(|timing| {
+            let previous_time = previous
+                .rows
+                .get(&timing.day)
+                .and_then(|row| previous.parts_time(row));
+            format_row(config, year, timing, previous_time)
+        })
+        .collect();
+
+    let table = format_table("##", config, rows, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
+/// Returns whether the benchmark table in the readme holds the timings of `year`.
+/// The table has no year column and is keyed by day only, so it tracks the configured year;
+/// timings of other years would overwrite its rows.
+#[must_use]
+pub fn tracks_year(year: Year) -> bool {
+    default_year().is_none_or(|configured| configured == year)
+}
+
+/// Replaces the row of a single day in an existing table, keeping the rows of other days.
+/// Returns the recomputed total in milliseconds.
+fn update_day_content(
+    s: &mut String,
+    config: &Config,
+    year: Year,
+    timing: &Timings,
+) -> Result<f64, Error> {
+    let positions = locate_table(s, &config.benchmark_marker)?;
+    let mut table = ParsedTable::parse(&s[positions.pos_start..positions.pos_end]);
+    let header = table_header(config);
+
+    if !table.header.is_empty() && table.header != header {
+        return Err(Error::Parser(
+            "the columns of the benchmark table changed, run `cargo time` to regenerate it.".into(),
+        ));
+    }
+
+    let previous_time = table
+        .rows
+        .get(&timing.day)
+        .and_then(|row| table.parts_time(row));
+    let row = format_row(config, year, timing, previous_time);
+    table.header = header;
+    table.rows.insert(timing.day, row);
+
+    let total_millis = table
+        .rows
+        .values()
+        .map(|row| table.total(row))
+        .sum::<Duration>()
+        .as_secs_f64()
+        * 1000_f64;
+
+    let rows = table.rows.into_values().collect();
+    let content = format_table("##", config, rows, total_millis);
+    s.replace_range(positions.pos_start..positions.pos_end, &content);
+    Ok(total_millis)
+}
+
+/// Updates the row of a single day in the benchmark table of the readme configured in the project config.
+/// Returns the recomputed total in milliseconds.
+pub fn update_day(year: Year, timing: &Timings) -> Result<f64, Error> {
+    let config = config::get();
+    let path = &config.readme;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    let total_millis = update_day_content(&mut readme, config, year, timing)?;
     fs::write(path, &readme)?;
+
+    if let Some(chart) = &config.benchmark_chart {
+        let positions = locate_table(&readme, &config.benchmark_marker)?;
+        let table = ParsedTable::parse(&readme[positions.pos_start..positions.pos_end]);
+        fs::write(chart, chart::render(&table.timings()))?;
+    }
+
+    Ok(total_millis)
+}
+
+/// Replaces the benchmark table in the readme configured in the project config.
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+    let config = config::get();
+    let path = &config.readme;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    if let Some(chart) = &config.benchmark_chart {
+        fs::write(chart, chart::render(&timings))?;
+    }
+
+    update_content(&mut readme, config, year, timings, total_millis)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/// Writes timings to a JSON file.
+#[cfg(feature = "serde")]
+pub fn write_json(path: &str, timings: &[Timings]) -> Result<(), Error> {
+    fs::write(path, serde_json::to_string_pretty(timings)?)?;
     Ok(())
 }
 
+/// Reads timings from a JSON file written by [`write_json`].
+#[cfg(feature = "serde")]
+pub fn read_json(path: &str) -> Result<Vec<Timings>, Error> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use std::time::Duration;
+
+    use super::{
+        parse_duration, relative_link, update_content, update_day_content, Column, Timings, MARKER,
+    };
+    use crate::template::config::Config;
+    use crate::template::runner::Measurement;
+    use crate::{day, year};
+
+    fn measurement(millis: u64) -> Option<Measurement> {
+        Some(Measurement {
+            mean: Duration::from_millis(millis),
+            stddev: Duration::from_micros(millis * 10),
+            samples: 100,
+        })
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
-                part_1: Some("10ms".into()),
-                part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
+                parse: None,
+                part_1: measurement(10),
+                part_2: measurement(20),
+                peak_memory: Some(2048),
+                total: Duration::from_millis(30),
             },
             Timings {
                 day: day!(2),
-                part_1: Some("30ms".into()),
-                part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
+                parse: None,
+                part_1: measurement(30),
+                part_2: measurement(40),
+                peak_memory: Some(2048),
+                total: Duration::from_millis(70),
             },
             Timings {
                 day: day!(4),
-                part_1: Some("40ms".into()),
-                part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
+                parse: None,
+                part_1: measurement(40),
+                part_2: measurement(50),
+                peak_memory: Some(2048),
+                total: Duration::from_millis(90),
             },
         ]
     }
@@ -134,28 +549,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +613,14 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -171,10 +628,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2020/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2020/02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2020/04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +640,151 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_optional_columns() {
+        let config = Config {
+            benchmark_columns: vec![
+                Column::Parse,
+                Column::Samples,
+                Column::Stddev,
+                Column::Memory,
+                Column::Delta,
+                Column::Link,
+            ],
+            ..Config::default()
+        };
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &config, year!(2020), get_mock_timings(), 190.0).unwrap();
+
+        let mut timings = get_mock_timings();
+        timings[0].parse = measurement(1);
+        timings[0].part_1 = measurement(40);
+        timings.truncate(1);
+        update_content(&mut s, &config, year!(2020), timings, 190.0).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Parse | Part 1 | Part 2 | Samples | Std. dev. | Peak memory | Δ | Puzzle |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2020/01.rs) | `1.0ms` | `40.0ms` | `20.0ms` | 100 / 100 | `±400.0µs` / `±200.0µs` | 2.0 MiB | +100.0% | [Puzzle](https://adventofcode.com/2020/day/1) |"
+        );
+    }
+
+    #[test]
+    fn updates_single_day() {
+        let config = Config::default();
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(&mut s, &config, year!(2020), get_mock_timings(), 190.0).unwrap();
+
+        let mut timing = get_mock_timings().remove(1);
+        timing.part_1 = measurement(10);
+        timing.part_2 = measurement(10);
+        let total = update_day_content(&mut s, &config, year!(2020), &timing).unwrap();
+        assert!((total - 140.0).abs() < 1e-9);
+
+        timing.day = day!(3);
+        timing.part_1 = measurement(5);
+        timing.part_2 = measurement(5);
+        update_day_content(&mut s, &config, year!(2020), &timing).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2020/01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2020/02.rs) | `10.0ms` | `10.0ms` |",
+            "| [Day 3](./src/bin/2020/03.rs) | `5.0ms` | `5.0ms` |",
+            "| [Day 4](./src/bin/2020/04.rs) | `40.0ms` | `50.0ms` |",
+            "",
+            "**Total: 150.00ms**",
+            "<!--- benchmarking table --->",
+            "baz",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn updates_single_day_in_empty_table() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let timing = get_mock_timings().remove(0);
+        update_day_content(&mut s, &Config::default(), year!(2020), &timing).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2020/01.rs) | `10.0ms` | `20.0ms` |"));
+        assert!(s.contains("**Total: 30.00ms**"));
+    }
+
+    #[test]
+    fn errors_if_columns_changed() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            &Config::default(),
+            year!(2020),
+            get_mock_timings(),
+            190.0,
+        )
+        .unwrap();
+
+        let config = Config {
+            benchmark_columns: vec![Column::Samples],
+            ..Config::default()
+        };
+        let timing = get_mock_timings().remove(0);
+        assert!(update_day_content(&mut s, &config, year!(2020), &timing).is_err());
+    }
+
+    #[test]
+    fn links_chart_relative_to_readme() {
+        assert_eq!(relative_link("README.md", "bench.svg"), "bench.svg");
+        assert_eq!(
+            relative_link("./README.md", "docs/bench.svg"),
+            "docs/bench.svg"
+        );
+        assert_eq!(
+            relative_link("docs/README.md", "docs/bench.svg"),
+            "bench.svg"
+        );
+        assert_eq!(
+            relative_link("docs/2020/README.md", "assets/bench.svg"),
+            "../../assets/bench.svg"
+        );
+
+        let config = Config {
+            readme: "docs/README.md".into(),
+            benchmark_chart: Some("assets/bench.svg".into()),
+            ..Config::default()
+        };
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &config, year!(2020), get_mock_timings(), 190.0).unwrap();
+        assert!(s.contains("![Benchmark chart](../assets/bench.svg)"));
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("74.1ns"), Some(Duration::from_nanos(74)));
+        assert_eq!(parse_duration("1.5µs"), Some(Duration::from_nanos(1500)));
+        assert_eq!(parse_duration("20.0ms"), Some(Duration::from_millis(20)));
+        assert_eq!(parse_duration("2s"), Some(Duration::from_secs(2)));
+        assert_eq!(parse_duration("-"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn roundtrips_json() {
+        let timings = get_mock_timings();
+        let json = serde_json::to_string(&timings).unwrap();
+        assert!(json.contains(r#""day":1"#));
+        assert_eq!(
+            serde_json::from_str::<Vec<Timings>>(&json).unwrap(),
+            timings
+        );
+    }
 }
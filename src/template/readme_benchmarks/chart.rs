@@ -0,0 +1,193 @@
+/// Renders benchmark timings as an SVG bar chart.
+/// Times of solutions span several orders of magnitude, so the chart uses a logarithmic scale.
+use std::fmt::Write;
+
+use super::Timings;
+use crate::template::runner::Measurement;
+
+const DAY_WIDTH: f64 = 30.0;
+const BAR_WIDTH: f64 = 11.0;
+const PLOT_HEIGHT: f64 = 200.0;
+const MARGIN_LEFT: f64 = 60.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+
+const COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+
+/// Formats a power of ten of nanoseconds, e.g. `100µs` for 5.
+fn format_decade(exponent: i32) -> String {
+    if exponent < 0 {
+        return format!("0.{}1ns", "0".repeat(exponent.unsigned_abs() as usize - 1));
+    }
+
+    let units = ["ns", "µs", "ms", "s"];
+    let exponent = exponent.unsigned_abs();
+    let unit = (exponent / 3).min(3);
+    format!(
+        "{}{}",
+        10_u64.pow(exponent - unit * 3),
+        units[unit as usize]
+    )
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn nanos(measurement: &Measurement) -> f64 {
+    (measurement.mean.as_nanos() as f64).max(1.0)
+}
+
+/// Renders the part times of every day as grouped bars.
+#[must_use]
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+pub fn render(timings: &[Timings]) -> String {
+    let values: Vec<f64> = timings
+        .iter()
+        .flat_map(|t| [t.part_1.as_ref(), t.part_2.as_ref()])
+        .flatten()
+        .map(nanos)
+        .collect();
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let (low, high) = if values.is_empty() {
+        (0, 1)
+    } else {
+        // start a decade below the fastest time, so that its bar is visible even if it is a power of ten.
+        let low = min.log10().ceil() as i32 - 1;
+        (low, (max.log10().ceil() as i32).max(low + 1))
+    };
+
+    // leave enough room for the legend when only a few days are solved.
+    let width = MARGIN_LEFT + (DAY_WIDTH * timings.len() as f64).max(140.0) + MARGIN_RIGHT;
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let bottom = MARGIN_TOP + PLOT_HEIGHT;
+    let y = |nanos: f64| {
+        bottom - PLOT_HEIGHT * (nanos.log10() - f64::from(low)) / f64::from(high - low)
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{width}" height="{height}" fill="#ffffff"/>"##
+    );
+
+    for exponent in low..=high {
+        let line_y = y(10_f64.powi(exponent));
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{line_y:.1}" x2="{:.1}" y2="{line_y:.1}" stroke="#dddddd"/>"##,
+            width - MARGIN_RIGHT
+        );
+        let _ = writeln!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" text-anchor="end" fill="#555555">{}</text>"##,
+            MARGIN_LEFT - 6.0,
+            line_y + 4.0,
+            format_decade(exponent)
+        );
+    }
+
+    for (i, timing) in timings.iter().enumerate() {
+        let day_x = MARGIN_LEFT + DAY_WIDTH * i as f64;
+
+        for (part, measurement) in [&timing.part_1, &timing.part_2].into_iter().enumerate() {
+            let Some(measurement) = measurement else {
+                continue;
+            };
+            let top = y(nanos(measurement));
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{:.1}" y="{top:.1}" width="{BAR_WIDTH}" height="{:.1}" fill="{}"><title>Day {} part {}: {:.1?}</title></rect>"#,
+                day_x + 4.0 + BAR_WIDTH * part as f64,
+                bottom - top,
+                COLORS[part],
+                timing.day.into_inner(),
+                part + 1,
+                measurement.mean
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" text-anchor="middle" fill="#555555">{}</text>"##,
+            day_x + DAY_WIDTH / 2.0,
+            bottom + 16.0,
+            timing.day.into_inner()
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r##"<text x="{:.1}" y="{:.1}" text-anchor="middle" fill="#555555">Day</text>"##,
+        MARGIN_LEFT + (width - MARGIN_LEFT - MARGIN_RIGHT) / 2.0,
+        bottom + 32.0
+    );
+
+    for (part, color) in COLORS.iter().enumerate() {
+        let x = MARGIN_LEFT + 70.0 * part as f64;
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x:.1}" y="14" width="10" height="10" fill="{color}"/><text x="{:.1}" y="23">Part {}</text>"#,
+            x + 14.0,
+            part + 1
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_decade, render};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::runner::Measurement;
+
+    fn measurement(nanos: u64) -> Option<Measurement> {
+        Some(Measurement {
+            mean: Duration::from_nanos(nanos),
+            stddev: Duration::ZERO,
+            samples: 1,
+        })
+    }
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(-1), "0.1ns");
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(3), "1µs");
+        assert_eq!(format_decade(7), "10ms");
+        assert_eq!(format_decade(10), "10s");
+    }
+
+    #[test]
+    fn renders_bars_on_log_scale() {
+        let timings = [Timings {
+            day: day!(1),
+            parse: None,
+            part_1: measurement(1_000),
+            part_2: measurement(1_000_000),
+            peak_memory: None,
+            total: Duration::from_nanos(1_001_000),
+        }];
+
+        let svg = render(&timings);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        // the axis spans 100ns to 1ms, so part 1 fills the lowest decade and part 2 the whole plot.
+        assert!(svg.contains(r#"y="190.0" width="11" height="50.0""#));
+        assert!(svg.contains(r#"y="40.0" width="11" height="200.0""#));
+        assert!(!svg.contains(r#"height="0.0""#));
+        assert!(svg.contains("<title>Day 1 part 2: 1.0ms</title>"));
+        assert!(svg.contains(">100ns</text>"));
+        assert!(svg.contains(">1ms</text>"));
+    }
+}
@@ -0,0 +1,304 @@
+/// Module that updates the readme with the stars collected so far.
+/// Stars are derived from the answer store and the submission log, so no requests to the website are needed.
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::{fs, io};
+
+use crate::template::readme_benchmarks::{self, locate_table};
+use crate::template::submissions::{self, Outcome};
+use crate::template::{answers, aoc_web, config};
+use crate::{Calendar, Day, Part, PuzzleId, Year};
+
+/// The default marker that delimits the progress table, see [`config::Config::progress_marker`].
+pub const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+    Submissions(submissions::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(msg) => write!(f, "{msg}"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Submissions(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<submissions::Error> for Error {
+    fn from(e: submissions::Error) -> Self {
+        Error::Submissions(e)
+    }
+}
+
+impl From<readme_benchmarks::Error> for Error {
+    fn from(e: readme_benchmarks::Error) -> Self {
+        match e {
+            readme_benchmarks::Error::IO(e) => Error::IO(e),
+            e => Error::Parser(e.to_string()),
+        }
+    }
+}
+
+/// The stars collected for one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
+}
+
+impl DayProgress {
+    fn stars(self) -> usize {
+        usize::from(self.part_1) + usize::from(self.part_2)
+    }
+}
+
+/// Returns the progress of every day with at least one star, given the set of solved puzzles.
+/// Days with fewer parts than the others (e.g. the final day) receive their missing stars once every other part is solved,
+/// as on the website.
+#[must_use]
+pub fn progress(calendar: &Calendar, year: Year, solved: &BTreeSet<PuzzleId>) -> Vec<DayProgress> {
+    let all_solved = calendar.days().all(|day| {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| calendar.has_part(day, part))
+            .all(|part| solved.contains(&PuzzleId::new(year, day, part)))
+    });
+
+    calendar
+        .days()
+        .map(|day| {
+            let is_solved = |part| {
+                if calendar.has_part(day, part) {
+                    solved.contains(&PuzzleId::new(year, day, part))
+                } else {
+                    all_solved
+                }
+            };
+
+            DayProgress {
+                day,
+                part_1: is_solved(Part::One),
+                part_2: is_solved(Part::Two),
+            }
+        })
+        .filter(|progress| progress.stars() > 0)
+        .collect()
+}
+
+/// Collects the solved puzzles of `year` from the answer store and the correct answers in the submission log.
+pub fn solved(calendar: &Calendar, year: Year) -> Result<BTreeSet<PuzzleId>, Error> {
+    let mut solved: BTreeSet<PuzzleId> = calendar
+        .days()
+        .flat_map(|day| {
+            let [part_1, part_2] = answers::read(year, day);
+            [(Part::One, part_1), (Part::Two, part_2)]
+                .into_iter()
+                .filter(|(_, answer)| answer.is_some())
+                .map(move |(part, _)| PuzzleId::new(year, day, part))
+        })
+        .collect();
+
+    solved.extend(
+        submissions::read(year)?
+            .into_iter()
+            .filter(|submission| submission.outcome == Outcome::Correct)
+            .map(|submission| submission.puzzle),
+    );
+
+    Ok(solved)
+}
+
+fn format_star(solved: bool) -> &'static str {
+    if solved {
+        "⭐"
+    } else {
+        ""
+    }
+}
+
+fn format_table(marker: &str, calendar: &Calendar, year: Year, progress: &[DayProgress]) -> String {
+    let mut lines: Vec<String> = vec![
+        marker.into(),
+        format!("## {year} Results"),
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: |".into(),
+    ];
+
+    for day in progress {
+        lines.push(format!(
+            "| [Day {}]({}) | {} | {} |",
+            day.day.into_inner(),
+            aoc_web::puzzle_url(year, day.day),
+            format_star(day.part_1),
+            format_star(day.part_2)
+        ));
+    }
+
+    let stars: usize = progress.iter().map(|day| day.stars()).sum();
+    let max_stars = usize::from(calendar.day_count()) * usize::from(calendar.parts_per_day());
+
+    lines.push(String::new());
+    lines.push(format!("**Stars: {stars}/{max_stars}**"));
+    lines.push(marker.into());
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    calendar: &Calendar,
+    year: Year,
+    progress: &[DayProgress],
+) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    let table = format_table(marker, calendar, year, progress);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Replaces the progress table in the readme configured in the project config.
+/// Returns the number of collected stars.
+pub fn update(year: Year) -> Result<usize, Error> {
+    let config = config::get();
+    let calendar = Calendar::for_year(year);
+    let progress = progress(&calendar, year, &solved(&calendar, year)?);
+
+    let path = &config.readme;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        &config.progress_marker,
+        &calendar,
+        year,
+        &progress,
+    )?;
+    fs::write(path, &readme)?;
+
+    Ok(progress.iter().map(|day| day.stars()).sum())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{progress, update_content, DayProgress, MARKER};
+    use crate::{day, year, Calendar, Part, PuzzleId};
+
+    fn solved(puzzles: &[(u8, Part)]) -> BTreeSet<PuzzleId> {
+        puzzles
+            .iter()
+            .map(|&(day, part)| {
+                PuzzleId::new(
+                    year!(2020),
+                    Calendar::ADVENT_OF_CODE.day(day).unwrap(),
+                    part,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn collects_progress() {
+        let calendar = Calendar::ADVENT_OF_CODE;
+        let progress = progress(
+            &calendar,
+            year!(2020),
+            &solved(&[
+                (1, Part::One),
+                (1, Part::Two),
+                (3, Part::One),
+                (25, Part::One),
+            ]),
+        );
+
+        assert_eq!(
+            progress,
+            vec![
+                DayProgress {
+                    day: day!(1),
+                    part_1: true,
+                    part_2: true,
+                },
+                DayProgress {
+                    day: day!(3),
+                    part_1: true,
+                    part_2: false,
+                },
+                DayProgress {
+                    day: day!(25),
+                    part_1: true,
+                    part_2: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn awards_final_star() {
+        let calendar = Calendar::ADVENT_OF_CODE;
+        let puzzles: Vec<_> = (1..=25)
+            .flat_map(|day| [(day, Part::One), (day, Part::Two)])
+            .filter(|&(day, part)| day != 25 || part == Part::One)
+            .collect();
+        let progress = progress(&calendar, year!(2020), &solved(&puzzles));
+
+        assert_eq!(progress.len(), 25);
+        assert!(progress.iter().all(|day| day.part_1 && day.part_2));
+    }
+
+    #[test]
+    fn updates_progress_table() {
+        let calendar = Calendar::ADVENT_OF_CODE;
+        let mut s = format!("foo\n{MARKER}\n{MARKER}\nbar");
+        let progress = progress(
+            &calendar,
+            year!(2020),
+            &solved(&[(1, Part::One), (1, Part::Two), (2, Part::One)]),
+        );
+
+        update_content(&mut s, MARKER, &calendar, year!(2020), &progress).unwrap();
+
+        let expected = [
+            "foo",
+            MARKER,
+            "## 2020 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2020/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2020/day/2) | ⭐ |  |",
+            "",
+            "**Stars: 3/50**",
+            MARKER,
+            "bar",
+        ]
+        .join("\n");
+
+        assert_eq!(s, expected);
+
+        update_content(&mut s, MARKER, &calendar, year!(2020), &[]).unwrap();
+        assert!(s.contains("**Stars: 0/50**"));
+        assert_eq!(s.matches(MARKER).count(), 2);
+    }
+
+    #[test]
+    fn errors_if_marker_not_present() {
+        let calendar = Calendar::ADVENT_OF_CODE;
+        let mut s = "foo".to_string();
+        assert!(update_content(&mut s, MARKER, &calendar, year!(2020), &[]).is_err());
+    }
+}
@@ -1,63 +1,144 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::submissions::{self, Outcome};
+use crate::template::{answers, aoc_cli, readme_progress, ANSI_ITALIC, ANSI_RESET};
+use crate::{Part, PuzzleId};
 use std::fmt::Display;
 use std::io::{stdout, Write};
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// The execution time of a function, averaged over a number of samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Measurement {
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub samples: u128,
+}
+
+/// The result of running one part of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartResult {
+    pub puzzle: PuzzleId,
+    pub answer: Option<String>,
+    pub measurement: Measurement,
+}
 
-    let (result, duration, samples) =
+pub fn run_part<I: Clone, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+) -> PartResult {
+    let part_str = format!("Part {}", puzzle.part);
+
+    let (result, measurement) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(&result, &part_str, &format_measurement(&measurement));
+    check_answer(&result, puzzle);
+
+    let answer = result.as_ref().map(ToString::to_string);
+
+    if let Some(Ok(output)) = result
+        .as_ref()
+        .and_then(|result| submit_result(result, puzzle))
+    {
+        record_submission(puzzle, answer.as_deref().unwrap_or_default(), &output);
+    }
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    PartResult {
+        puzzle,
+        answer,
+        measurement,
+    }
+}
+
+/// Runs the parse step of a solution that splits parsing from solving, timing it like a part.
+pub fn run_parse<I: Clone, T>(func: impl Fn(I) -> T, input: I) -> T {
+    print!("Parse: …");
+    let _ = stdout().flush();
+
+    let (result, measurement) = run_timed(func, input, |_| {});
+
+    print!("\r");
+    println!("Parse:{}", format_measurement(&measurement));
+    result
+}
+
+/// Prints the peak memory usage of the process when benchmarking.
+/// Only supported on Linux, where it is read from `/proc/self/status`.
+pub fn print_peak_memory() {
+    if !env::args().any(|x| x == "--time") {
+        return;
+    }
+
+    let peak_kib = fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("VmHWM:"))
+                .and_then(|value| {
+                    value
+                        .trim()
+                        .trim_end_matches("kB")
+                        .trim()
+                        .parse::<u64>()
+                        .ok()
+                })
+        });
+
+    if let Some(peak_kib) = peak_kib {
+        println!("Peak memory: {peak_kib} KiB");
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
-    input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+fn run_timed<I: Clone, T>(func: impl Fn(I) -> T, input: I, hook: impl Fn(&T)) -> (T, Measurement) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let measurement = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        Measurement {
+            mean: base_time,
+            stddev: Duration::ZERO,
+            samples: 1,
+        }
     };
 
-    (result, run.0, run.1)
+    (result, measurement)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Measurement {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    sample(func, input, base_time)
+}
+
+/// Measures the execution time of a function like `--time` does, without printing anything.
+pub fn measure<I: Clone, T>(func: impl Fn(I) -> T, input: I) -> Measurement {
+    let timer = Instant::now();
+    func(input.clone());
+    sample(func, input, &timer.elapsed())
+}
+
+fn sample<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> Measurement {
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -69,11 +150,14 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let mean = average_duration(&timers);
+
+    #[allow(clippy::cast_possible_truncation)]
+    Measurement {
+        mean: Duration::from_nanos(mean as u64),
+        stddev: Duration::from_nanos(standard_deviation(&timers, mean) as u64),
+        samples: bench_iterations,
+    }
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -84,11 +168,32 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn standard_deviation(numbers: &[Duration], mean: u128) -> u128 {
+    let variance = numbers
+        .iter()
+        .map(|d| (d.as_nanos() as f64 - mean as f64).powi(2))
+        .sum::<f64>()
+        / numbers.len() as f64;
+
+    variance.sqrt() as u128
+}
+
+fn format_measurement(measurement: &Measurement) -> String {
+    let Measurement {
+        mean,
+        stddev,
+        samples,
+    } = measurement;
+
+    if *samples == 1 {
+        format!(" ({mean:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({mean:.1?} ± {stddev:.1?} @ {samples} samples)")
     }
 }
 
@@ -127,33 +232,63 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Warn if a result does not match the accepted answer recorded for this part.
+fn check_answer<T: Display>(result: &Option<T>, puzzle: PuzzleId) {
+    let Some(expected) = answers::read_part(puzzle) else {
+        return;
+    };
+
+    let actual = result.as_ref().map(ToString::to_string);
+    if actual.as_deref() != Some(expected.as_str()) {
+        println!("  ⚠ {ANSI_BOLD}regression{ANSI_RESET}: accepted answer is {ANSI_BOLD}{expected}{ANSI_RESET}");
+    }
+}
+
+/// Appends a submitted answer to the submission log and updates the progress in the readme if it was correct.
+fn record_submission(puzzle: PuzzleId, answer: &str, output: &Output) {
+    let outcome = Outcome::from_response(&String::from_utf8_lossy(&output.stdout));
+
+    if let Err(e) = submissions::record(puzzle, answer, outcome) {
+        eprintln!("failed to record submission: {e}");
+        return;
+    }
+
+    if outcome == Outcome::Correct {
+        match readme_progress::update(puzzle.year) {
+            Ok(stars) => println!("🎄 Updated README progress, {stars} stars collected."),
+            Err(e) => eprintln!("failed to update README progress: {e}"),
+        }
+    }
+}
+
+/// Parse the puzzle passed via `--submit`, either as a part (`2`) or a full puzzle id (`2020/08/2`).
+fn parse_submit_arg(arg: &str, puzzle: PuzzleId) -> Option<PuzzleId> {
+    arg.parse::<PuzzleId>().ok().or_else(|| {
+        let part: Part = arg.parse().ok()?;
+        Some(PuzzleId { part, ..puzzle })
+    })
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
-    part: u8,
+    puzzle: PuzzleId,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
     let args: Vec<String> = env::args().collect();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
-
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    }
-
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    let submit_index = args.iter().position(|x| x == "--submit")?;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+    let Some(submit) = args
+        .get(submit_index + 1)
+        .and_then(|arg| parse_submit_arg(arg, puzzle))
+    else {
         eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
         process::exit(1);
     };
 
-    if part_submit != part {
+    if submit != puzzle {
         return None;
     }
 
@@ -163,5 +298,5 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(aoc_cli::submit(puzzle, &result.to_string()))
 }
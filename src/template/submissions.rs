@@ -0,0 +1,219 @@
+/// Module that keeps a log of the answers submitted via aoc-cli, together with the response of the website.
+/// Every submission appends one JSON line to `data/<year>/submissions.jsonl`.
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+
+use serde_json::{json, Value};
+
+use crate::template::{datetime, get_data_dir};
+use crate::{PuzzleId, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Json(serde_json::Error),
+    Format(String),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Json(e) => write!(f, "invalid submission entry: {e}"),
+            Error::Format(field) => write!(f, "unexpected submission format: {field}"),
+        }
+    }
+}
+
+#[must_use]
+pub fn get_submissions_path(year: Year) -> String {
+    format!("{}/submissions.jsonl", get_data_dir(year))
+}
+
+/// The response of the website to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    /// The answer was submitted too soon after a previous one.
+    TooSoon,
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    /// Classifies the response printed by aoc-cli.
+    #[must_use]
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::TooSoon
+        } else if response.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Incorrect => "incorrect",
+            Outcome::TooSoon => "too_soon",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        [
+            Outcome::Correct,
+            Outcome::Incorrect,
+            Outcome::TooSoon,
+            Outcome::WrongLevel,
+            Outcome::Unknown,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.as_str() == s)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One answer submitted for a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    /// Unix timestamp of the submission.
+    pub timestamp: i64,
+    pub puzzle: PuzzleId,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Submission {
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        json!({
+            "timestamp": self.timestamp,
+            "puzzle": self.puzzle.to_string(),
+            "answer": self.answer,
+            "outcome": self.outcome.as_str(),
+        })
+        .to_string()
+    }
+
+    pub fn from_json(line: &str) -> Result<Self, Error> {
+        let value: Value = serde_json::from_str(line)?;
+        let string = |key: &str| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| Error::Format(key.into()))
+        };
+
+        Ok(Self {
+            timestamp: value
+                .get("timestamp")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| Error::Format("timestamp".into()))?,
+            puzzle: string("puzzle")?
+                .parse()
+                .map_err(|_| Error::Format("puzzle".into()))?,
+            answer: string("answer")?.to_string(),
+            outcome: Outcome::parse(string("outcome")?)
+                .ok_or_else(|| Error::Format("outcome".into()))?,
+        })
+    }
+}
+
+/// Appends a submission to the log of its year and returns it.
+pub fn record(puzzle: PuzzleId, answer: &str, outcome: Outcome) -> Result<Submission, Error> {
+    let submission = Submission {
+        timestamp: datetime::now_timestamp(),
+        puzzle,
+        answer: answer.to_string(),
+        outcome,
+    };
+
+    fs::create_dir_all(get_data_dir(puzzle.year))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_submissions_path(puzzle.year))?;
+    file.write_all(format!("{}\n", submission.to_json()).as_bytes())?;
+    Ok(submission)
+}
+
+/// Reads all submissions of `year`, oldest first.
+pub fn read(year: Year) -> Result<Vec<Submission>, Error> {
+    let content = match fs::read_to_string(get_submissions_path(year)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Submission::from_json)
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, Submission};
+    use crate::{day, year, Part, PuzzleId};
+
+    #[test]
+    fn classifies_responses() {
+        let response =
+            "That's the right answer! You are one gold star closer to saving your vacation.";
+        assert_eq!(Outcome::from_response(response), Outcome::Correct);
+        let response = "That's not the right answer; your answer is too low.";
+        assert_eq!(Outcome::from_response(response), Outcome::Incorrect);
+        let response =
+            "You gave an answer too recently; you have to wait after submitting an answer.";
+        assert_eq!(Outcome::from_response(response), Outcome::TooSoon);
+        let response =
+            "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Outcome::from_response(response), Outcome::WrongLevel);
+        assert_eq!(Outcome::from_response(""), Outcome::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submission = Submission {
+            timestamp: 1_607_058_000,
+            puzzle: PuzzleId::new(year!(2020), day!(4), Part::Two),
+            answer: "abc \"def\"".into(),
+            outcome: Outcome::TooSoon,
+        };
+
+        assert_eq!(
+            Submission::from_json(&submission.to_json()).unwrap(),
+            submission
+        );
+        assert!(Submission::from_json("{\"timestamp\": 0}").is_err());
+    }
+}
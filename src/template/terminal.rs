@@ -0,0 +1,71 @@
+/// Helpers for writing long-form output to the terminal.
+use std::{
+    env,
+    fs::File,
+    io::{stdout, IsTerminal, Write},
+    process::{Command, Stdio},
+};
+
+const DEFAULT_SIZE: (usize, usize) = (80, 24);
+
+/// Returns the size of the terminal as `(columns, rows)`.
+/// Falls back to 80x24 if the size cannot be determined.
+#[must_use]
+pub fn size() -> (usize, usize) {
+    let from_env = |key: &str| env::var(key).ok().and_then(|x| x.parse().ok());
+
+    if let (Some(cols), Some(rows)) = (from_env("COLUMNS"), from_env("LINES")) {
+        return (cols, rows);
+    }
+
+    let Ok(tty) = File::open("/dev/tty") else {
+        return DEFAULT_SIZE;
+    };
+
+    Command::new("stty")
+        .arg("size")
+        .stdin(tty)
+        .stderr(Stdio::null())
+        .output()
+        .ok()
+        .and_then(|output| {
+            let output = String::from_utf8_lossy(&output.stdout).to_string();
+            let (rows, cols) = output.trim().split_once(' ')?;
+            Some((cols.parse().ok()?, rows.parse().ok()?))
+        })
+        .unwrap_or(DEFAULT_SIZE)
+}
+
+/// Prints `content` to stdout. If stdout is a terminal and the content does not fit on one screen,
+/// the content is piped through `$PAGER` (or `less -R` if unset).
+pub fn page(content: &str) {
+    let (_, rows) = size();
+
+    if !stdout().is_terminal() || content.lines().count() < rows {
+        println!("{content}");
+        return;
+    }
+
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut pager_args = pager.split_whitespace();
+
+    let spawned = pager_args.next().and_then(|cmd| {
+        Command::new(cmd)
+            .args(pager_args)
+            .stdin(Stdio::piped())
+            .spawn()
+            .ok()
+    });
+
+    let Some(mut child) = spawned else {
+        println!("{content}");
+        return;
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // the user may quit the pager before reading everything, ignore the resulting broken pipe.
+        let _ = writeln!(stdin, "{content}");
+    }
+
+    let _ = child.wait();
+}